@@ -443,7 +443,6 @@ fn devnet_testnet_genesis(
 				})
 				.collect(),
 		},
-		sudo: devnet::SudoConfig { key: Some(root_key.clone()) },
 		// no need to pass anything to aura, in fact it will panic if we do. Session will take care
 		// of this.
 		aura: Default::default(),
@@ -456,6 +455,7 @@ fn devnet_testnet_genesis(
 			],
 			accounts: vec![],
 		},
+		asset_policy: devnet::AssetPolicyConfig { issuer_only_precompiles: vec![2018] },
 		parachain_system: Default::default(),
 		polkadot_xcm: devnet::PolkadotXcmConfig {
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
@@ -466,6 +466,7 @@ fn devnet_testnet_genesis(
 			members: councillors,
 			phantom: Default::default(),
 		},
+		technical_membership: Default::default(),
 		treasury: Default::default(),
 
 		// EVM compatibility
@@ -552,7 +553,6 @@ fn mainnet_testnet_genesis(
 				})
 				.collect(),
 		},
-		sudo: mainnet::SudoConfig { key: Some(root_key.clone()) },
 		// no need to pass anything to aura, in fact it will panic if we do. Session will take care
 		// of this.
 		aura: Default::default(),
@@ -562,6 +562,7 @@ fn mainnet_testnet_genesis(
 			metadata: vec![(1984, b"Foreign USD".to_vec(), b"FUSD".to_vec(), 6)],
 			accounts: vec![],
 		},
+		asset_policy: Default::default(),
 		parachain_system: Default::default(),
 		polkadot_xcm: mainnet::PolkadotXcmConfig {
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
@@ -572,6 +573,7 @@ fn mainnet_testnet_genesis(
 			members: councillors,
 			phantom: Default::default(),
 		},
+		technical_membership: Default::default(),
 		treasury: Default::default(),
 
 		// EVM compatibility
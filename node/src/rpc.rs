@@ -25,6 +25,8 @@
 
 #![warn(missing_docs)]
 
+mod trapped_assets;
+
 use jsonrpsee::RpcModule;
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -151,6 +153,7 @@ where
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
+		+ pallet_trapped_assets_runtime_api::TrappedAssetsApi<Block>
 		+ BlockBuilder<Block>,
 	P: TransactionPool<Block = Block> + Sync + Send + 'static,
 	BE: Backend<Block> + 'static,
@@ -164,6 +167,7 @@ where
 	};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use trapped_assets::{TrappedAssets, TrappedAssetsApiServer};
 
 	let mut io = RpcModule::new(());
 	let FullDeps {
@@ -184,6 +188,7 @@ where
 
 	io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(TrappedAssets::new(client.clone()).into_rpc())?;
 
 	let signers = Vec::new();
 
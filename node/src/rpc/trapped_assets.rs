@@ -0,0 +1,108 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC to find the assets trapped by XCM messages from an origin, so that they can be claimed.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use pallet_trapped_assets_runtime_api::TrappedAssetsApi as TrappedAssetsRuntimeApi;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+use xcm::VersionedMultiLocation;
+
+/// Assets trapped by messages from an origin.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetTrap {
+	/// The hash the assets are trapped under in `pallet_xcm`.
+	pub hash: H256,
+	/// The SCALE encoded `VersionedMultiAssets` to claim.
+	pub assets: Bytes,
+	/// How many times the assets were trapped and not claimed.
+	pub count: u32,
+}
+
+/// Trapped assets RPC methods.
+#[rpc(server)]
+pub trait TrappedAssetsApi<BlockHash> {
+	/// Assets trapped by messages from `origin`, a SCALE encoded `VersionedMultiLocation`, and not
+	/// claimed yet.
+	///
+	/// Assets trapped before `pallet_trapped_assets` was added are only included once governance
+	/// recorded them with `record_trap`.
+	#[method(name = "xcm_trappedAssets")]
+	fn trapped_assets(&self, origin: Bytes, at: Option<BlockHash>) -> RpcResult<Vec<AssetTrap>>;
+}
+
+/// Provides the assets trapped by XCM, as recorded by the runtime.
+pub struct TrappedAssets<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> TrappedAssets<C, Block> {
+	/// Creates a new instance of the trapped assets RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> TrappedAssetsApiServer<<Block as BlockT>::Hash> for TrappedAssets<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TrappedAssetsRuntimeApi<Block>,
+{
+	fn trapped_assets(
+		&self,
+		origin: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AssetTrap>> {
+		let origin = VersionedMultiLocation::decode(&mut &origin[..]).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				ErrorCode::InvalidParams.code(),
+				"Unable to decode the origin.",
+				Some(e.to_string()),
+			))
+		})?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let traps = self.client.runtime_api().trapped_assets(at, origin).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Unable to query the trapped assets.",
+				Some(e.to_string()),
+			))
+		})?;
+
+		Ok(traps
+			.into_iter()
+			.map(|(hash, trap)| AssetTrap {
+				hash,
+				assets: trap.assets.encode().into(),
+				count: trap.count,
+			})
+			.collect())
+	}
+}
@@ -0,0 +1,94 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_core::H160;
+
+use super::{types::PrecompileRestriction, Pallet as AssetPolicy};
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn create_credential_type<T: Config>() -> CredentialOf<T> {
+	let credential: CredentialOf<T> = BoundedVec::truncate_from(b"kyc".to_vec());
+	assert_ok!(pallet_did::Pallet::<T>::add_credentials_type(
+		RawOrigin::Root.into(),
+		BoundedVec::truncate_from(sp_std::vec![credential.clone()])
+	));
+	credential
+}
+
+fn trusted_issuers<T: Config>(n: u32) -> BoundedVec<DidIdentifierOf<T>, T::MaxTrustedIssuers> {
+	BoundedVec::truncate_from(
+		(0..n).map(|i| account::<T::AccountId>("issuer", i, 0).into()).collect(),
+	)
+}
+
+benchmarks! {
+	where_clause { where T::AssetId: From<u32> }
+
+	set_asset_credential {
+		let i in 0 .. T::MaxTrustedIssuers::get();
+		let asset_id: T::AssetId = 1u32.into();
+		let credential = create_credential_type::<T>();
+		let issuers = trusted_issuers::<T>(i);
+	}: _(RawOrigin::Root, asset_id, credential.clone(), issuers.clone())
+	verify {
+		assert_eq!(AssetCredentials::<T>::get(asset_id), Some(credential.clone()));
+		assert_eq!(TrustedIssuers::<T>::get(asset_id), issuers);
+		assert_last_event::<T>(Event::AssetCredentialSet { asset_id, credential, issuers }.into());
+	}
+
+	remove_asset_credential {
+		let asset_id: T::AssetId = 1u32.into();
+		let credential = create_credential_type::<T>();
+		let issuers = trusted_issuers::<T>(T::MaxTrustedIssuers::get());
+		assert_ok!(AssetPolicy::<T>::set_asset_credential(RawOrigin::Root.into(), asset_id, credential, issuers));
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert_eq!(AssetCredentials::<T>::get(asset_id), None);
+		assert_last_event::<T>(Event::AssetCredentialRemoved { asset_id }.into());
+	}
+
+	set_precompile_restriction {
+		let c in 0 .. T::MaxAllowedCallers::get();
+		let asset_id: T::AssetId = 1u32.into();
+		let callers: BoundedVec<H160, T::MaxAllowedCallers> =
+			BoundedVec::truncate_from((0..c).map(|i| H160::from_low_u64_be(i as u64)).collect());
+		let restriction = PrecompileRestriction::<T>::Allowlist(callers);
+	}: _(RawOrigin::Root, asset_id, restriction.clone())
+	verify {
+		assert_eq!(PrecompileRestrictions::<T>::get(asset_id), Some(restriction.clone()));
+		assert_last_event::<T>(Event::PrecompileRestrictionSet { asset_id, restriction }.into());
+	}
+
+	remove_precompile_restriction {
+		let asset_id: T::AssetId = 1u32.into();
+		assert_ok!(AssetPolicy::<T>::set_precompile_restriction(
+			RawOrigin::Root.into(),
+			asset_id,
+			PrecompileRestriction::IssuerOnly
+		));
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert_eq!(PrecompileRestrictions::<T>::get(asset_id), None);
+		assert_last_event::<T>(Event::PrecompileRestrictionRemoved { asset_id }.into());
+	}
+}
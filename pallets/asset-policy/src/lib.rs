@@ -0,0 +1,422 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Asset Policy Pallet
+//!
+//! Lets governance attach a required credential type (see `pallet_did::CredentialsTypes`) to an
+//! asset, together with the issuers it trusts for that credential. Accounts can only move such an
+//! asset if their DID holds a credential of that type, issued by one of the trusted issuers that
+//! is still active.
+//!
+//! Only the trusted issuers of the asset are looked up, so the number of storage reads done by
+//! the checks below is bounded by `MaxTrustedIssuers`.
+//!
+//! Enforcement is split in two:
+//! - The sending side is enforced by setting this pallet as the `Freezer` of `pallet_assets`.
+//!   The whole balance of a non credentialed account is reported as frozen.
+//! - The receiving side is enforced by using [`CredentialedTransfers`] as (part of) the runtime
+//!   `BaseCallFilter`, which rejects `pallet_assets` transfers and mints to non credentialed
+//!   accounts, and by wrapping the XCM fungibles transactor of the runtime in
+//!   [`CredentialedDeposits`], which fails deposits and transfers to non credentialed accounts.
+//!
+//! Governance can also restrict who may call the ERC20 precompile of an asset, either to the
+//! asset issuer or to an allowlist of caller addresses. The runtime precompile set enforces it
+//! through [`Pallet::is_precompile_caller_allowed`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use crate::types::PrecompileRestriction;
+use frame_support::traits::{fungibles, Contains, EnsureOrigin, IsSubType};
+use pallet_did::{CredentialOf, DidIdentifierOf};
+use sp_core::H160;
+use sp_runtime::traits::StaticLookup;
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::latest::{Error as XcmError, MultiAsset, MultiLocation, Result as XcmResult, XcmContext};
+use xcm_executor::traits::{ConvertLocation, MatchesFungibles, TransactAsset};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Balance type of the assets controlled by the policy.
+pub type AssetBalanceOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_did::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the assets controlled by the policy.
+		type AssetId: Member + Parameter + MaxEncodedLen + MaybeSerializeDeserialize + Copy;
+
+		/// The assets the policy applies to.
+		type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>
+			+ fungibles::roles::Inspect<Self::AccountId>;
+
+		/// Origin allowed to set or remove asset policies.
+		type PolicyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of callers in a precompile allowlist.
+		#[pallet::constant]
+		type MaxAllowedCallers: Get<u32>;
+
+		/// The maximum number of issuers trusted for the credential of an asset.
+		#[pallet::constant]
+		type MaxTrustedIssuers: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Credential type required to send or receive an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_credential)]
+	pub type AssetCredentials<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, CredentialOf<T>>;
+
+	/// Issuers trusted for the credential required by an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn trusted_issuers)]
+	pub type TrustedIssuers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<DidIdentifierOf<T>, T::MaxTrustedIssuers>,
+		ValueQuery,
+	>;
+
+	/// Restrictions on who may call the ERC20 precompile of an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn precompile_restriction)]
+	pub type PrecompileRestrictions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, PrecompileRestriction<T>>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Assets whose ERC20 precompile can only be called by the asset issuer.
+		pub issuer_only_precompiles: Vec<T::AssetId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for asset_id in &self.issuer_only_precompiles {
+				PrecompileRestrictions::<T>::insert(asset_id, PrecompileRestriction::IssuerOnly);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Transfers of `asset_id` now require `credential`, issued by one of `issuers`.
+		AssetCredentialSet {
+			asset_id: T::AssetId,
+			credential: CredentialOf<T>,
+			issuers: BoundedVec<DidIdentifierOf<T>, T::MaxTrustedIssuers>,
+		},
+		/// Transfers of `asset_id` no longer require a credential.
+		AssetCredentialRemoved { asset_id: T::AssetId },
+		/// Calls to the ERC20 precompile of `asset_id` are now restricted by `restriction`.
+		PrecompileRestrictionSet { asset_id: T::AssetId, restriction: PrecompileRestriction<T> },
+		/// Anyone can call the ERC20 precompile of `asset_id` again.
+		PrecompileRestrictionRemoved { asset_id: T::AssetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The credential type is not registered in `pallet_did`
+		CredentialTypeDoesNotExist,
+		/// The asset does not require any credential
+		AssetCredentialNotSet,
+		/// The asset precompile is not restricted
+		PrecompileRestrictionNotSet,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Require `credential`, issued by one of `issuers`, for every transfer of `asset_id`.
+		/// Replaces any credential and issuers previously set for the asset.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_credential(issuers.len() as u32))]
+		pub fn set_asset_credential(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			credential: CredentialOf<T>,
+			issuers: BoundedVec<DidIdentifierOf<T>, T::MaxTrustedIssuers>,
+		) -> DispatchResult {
+			T::PolicyOrigin::ensure_origin(origin)?;
+			ensure!(
+				pallet_did::Pallet::<T>::credential_types().binary_search(&credential).is_ok(),
+				Error::<T>::CredentialTypeDoesNotExist
+			);
+
+			AssetCredentials::<T>::insert(asset_id, credential.clone());
+			TrustedIssuers::<T>::insert(asset_id, issuers.clone());
+			Self::deposit_event(Event::AssetCredentialSet { asset_id, credential, issuers });
+			Ok(())
+		}
+
+		/// Stop requiring a credential for transfers of `asset_id`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_asset_credential())]
+		pub fn remove_asset_credential(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+		) -> DispatchResult {
+			T::PolicyOrigin::ensure_origin(origin)?;
+			ensure!(
+				AssetCredentials::<T>::contains_key(asset_id),
+				Error::<T>::AssetCredentialNotSet
+			);
+
+			AssetCredentials::<T>::remove(asset_id);
+			TrustedIssuers::<T>::remove(asset_id);
+			Self::deposit_event(Event::AssetCredentialRemoved { asset_id });
+			Ok(())
+		}
+
+		/// Restrict who may call the ERC20 precompile of `asset_id`.
+		/// Replaces any restriction previously set for the asset.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_precompile_restriction(
+			T::MaxAllowedCallers::get()
+		))]
+		pub fn set_precompile_restriction(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			restriction: PrecompileRestriction<T>,
+		) -> DispatchResult {
+			T::PolicyOrigin::ensure_origin(origin)?;
+
+			PrecompileRestrictions::<T>::insert(asset_id, restriction.clone());
+			Self::deposit_event(Event::PrecompileRestrictionSet { asset_id, restriction });
+			Ok(())
+		}
+
+		/// Remove the restriction on who may call the ERC20 precompile of `asset_id`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_precompile_restriction())]
+		pub fn remove_precompile_restriction(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+		) -> DispatchResult {
+			T::PolicyOrigin::ensure_origin(origin)?;
+			ensure!(
+				PrecompileRestrictions::<T>::contains_key(asset_id),
+				Error::<T>::PrecompileRestrictionNotSet
+			);
+
+			PrecompileRestrictions::<T>::remove(asset_id);
+			Self::deposit_event(Event::PrecompileRestrictionRemoved { asset_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if `who` is allowed to send or receive `asset_id`.
+	/// Always true for assets without a required credential.
+	/// Reads at most `3 + 2 * MaxTrustedIssuers` storage items.
+	pub fn is_credentialed(asset_id: T::AssetId, who: &T::AccountId) -> bool {
+		match AssetCredentials::<T>::get(asset_id) {
+			Some(credential) => {
+				let did: DidIdentifierOf<T> = who.clone().into();
+				pallet_did::Pallet::<T>::has_valid_credential(
+					&did,
+					&credential,
+					&TrustedIssuers::<T>::get(asset_id),
+				)
+			},
+			None => true,
+		}
+	}
+
+	/// Returns true if `caller` may call the ERC20 precompile of `asset_id`.
+	/// `caller_account` is the account `caller` maps to, compared against the asset issuer.
+	pub fn is_precompile_caller_allowed(
+		asset_id: T::AssetId,
+		caller: H160,
+		caller_account: &T::AccountId,
+	) -> bool {
+		match PrecompileRestrictions::<T>::get(asset_id) {
+			Some(PrecompileRestriction::IssuerOnly) => {
+				<T::Assets as fungibles::roles::Inspect<T::AccountId>>::issuer(asset_id).as_ref()
+					== Some(caller_account)
+			},
+			Some(PrecompileRestriction::Allowlist(callers)) => callers.contains(&caller),
+			None => true,
+		}
+	}
+}
+
+impl<T: Config> pallet_assets::FrozenBalance<T::AssetId, T::AccountId, AssetBalanceOf<T>>
+	for Pallet<T>
+{
+	fn frozen_balance(asset_id: T::AssetId, who: &T::AccountId) -> Option<AssetBalanceOf<T>> {
+		// Non credentialed accounts can not move any of their balance
+		if Self::is_credentialed(asset_id, who) {
+			None
+		} else {
+			Some(<T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset_id, who))
+		}
+	}
+
+	fn died(_asset_id: T::AssetId, _who: &T::AccountId) {}
+}
+
+/// Call filter rejecting `pallet_assets` transfers and mints whose destination is not credentialed
+/// for the asset.
+pub struct CredentialedTransfers<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T, I> Contains<<T as frame_system::Config>::RuntimeCall> for CredentialedTransfers<T, I>
+where
+	T: Config + pallet_assets::Config<I, AssetId = <T as Config>::AssetId>,
+	I: 'static,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_assets::Call<T, I>>,
+{
+	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		let (id, dest) = match call.is_sub_type() {
+			Some(pallet_assets::Call::transfer { id, target, .. })
+			| Some(pallet_assets::Call::transfer_keep_alive { id, target, .. }) => (id, target),
+			Some(pallet_assets::Call::transfer_approved { id, destination, .. }) => {
+				(id, destination)
+			},
+			Some(pallet_assets::Call::force_transfer { id, dest, .. }) => (id, dest),
+			Some(pallet_assets::Call::mint { id, beneficiary, .. }) => (id, beneficiary),
+			_ => return true,
+		};
+
+		// Unknown destinations are rejected by `pallet_assets` itself
+		match T::Lookup::lookup(dest.clone()) {
+			Ok(who) => Pallet::<T>::is_credentialed((*id).into(), &who),
+			Err(_) => true,
+		}
+	}
+}
+
+/// Asset transactor wrapping `Transactor`, which only credits the assets it matches with
+/// `Matcher` to accounts credentialed for them.
+///
+/// Assets deposited or transferred over XCM are not dispatched through the runtime
+/// `BaseCallFilter`, so the runtime wraps its fungibles transactor with it. Assets not matched by
+/// `Matcher`, and locations `AccountIdConverter` does not convert, are left to `Transactor`.
+pub struct CredentialedDeposits<T, Transactor, Matcher, AccountIdConverter>(
+	PhantomData<(T, Transactor, Matcher, AccountIdConverter)>,
+);
+
+impl<T, Transactor, Matcher, AccountIdConverter>
+	CredentialedDeposits<T, Transactor, Matcher, AccountIdConverter>
+where
+	T: Config,
+	Matcher: MatchesFungibles<T::AssetId, AssetBalanceOf<T>>,
+	AccountIdConverter: ConvertLocation<T::AccountId>,
+{
+	fn ensure_credentialed(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		let (asset_id, _) = match Matcher::matches_fungibles(what) {
+			Ok(matched) => matched,
+			Err(_) => return Ok(()),
+		};
+		match AccountIdConverter::convert_location(who) {
+			Some(account) if !Pallet::<T>::is_credentialed(asset_id, &account) => {
+				Err(XcmError::FailedToTransactAsset("Destination is not credentialed"))
+			},
+			_ => Ok(()),
+		}
+	}
+}
+
+impl<T, Transactor, Matcher, AccountIdConverter> TransactAsset
+	for CredentialedDeposits<T, Transactor, Matcher, AccountIdConverter>
+where
+	T: Config,
+	Transactor: TransactAsset,
+	Matcher: MatchesFungibles<T::AssetId, AssetBalanceOf<T>>,
+	AccountIdConverter: ConvertLocation<T::AccountId>,
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: &XcmContext) -> XcmResult {
+		Self::ensure_credentialed(what, who)?;
+		Transactor::deposit_asset(what, who, context)
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		Transactor::withdraw_asset(what, who, maybe_context)
+	}
+
+	fn internal_transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		Self::ensure_credentialed(what, to)?;
+		Transactor::internal_transfer_asset(what, from, to, context)
+	}
+
+	fn transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		Self::ensure_credentialed(what, to)?;
+		Transactor::transfer_asset(what, from, to, context)
+	}
+}
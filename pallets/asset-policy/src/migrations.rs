@@ -0,0 +1,77 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	/// Restricts the ERC20 precompile of `IssuerOnlyAssets` to the asset issuer.
+	///
+	/// Replaces the `ASSET_PRECOMPILE_IDS` list that used to be hard-coded in the runtimes.
+	pub struct MigrateToV1<T, IssuerOnlyAssets>(PhantomData<(T, IssuerOnlyAssets)>);
+
+	impl<T: Config, IssuerOnlyAssets: Get<Vec<T::AssetId>>> OnRuntimeUpgrade
+		for MigrateToV1<T, IssuerOnlyAssets>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: "runtime::asset-policy",
+					"skipping v1 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let assets = IssuerOnlyAssets::get();
+			for asset_id in &assets {
+				PrecompileRestrictions::<T>::insert(asset_id, PrecompileRestriction::IssuerOnly);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::asset-policy",
+				"migrated {} issuer only asset precompiles",
+				assets.len()
+			);
+			T::DbWeight::get().reads_writes(1, assets.len() as u64 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"asset-policy storage version must be 1 after the migration"
+			);
+			for asset_id in IssuerOnlyAssets::get() {
+				ensure!(
+					PrecompileRestrictions::<T>::contains_key(asset_id),
+					"asset precompile restriction was not migrated"
+				);
+			}
+			Ok(())
+		}
+	}
+}
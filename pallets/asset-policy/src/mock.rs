@@ -0,0 +1,228 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+pub(crate) use crate as pallet_asset_policy;
+use frame_support::{
+	parameter_types,
+	traits::{
+		fungibles::Mutate, AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, NeverEnsureOrigin,
+	},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::{H160, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::Error as MatchError;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub struct Test {
+		System: frame_system::{Pallet, Call, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		DID: pallet_did,
+		AssetPolicy: pallet_asset_policy,
+	}
+);
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = CredentialedTransfers<Test>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = u64;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<0>;
+	type MetadataDepositBase = ConstU64<0>;
+	type MetadataDepositPerByte = ConstU64<0>;
+	type ApprovalDeposit = ConstU64<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = AssetPolicy;
+	type Extra = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	type CallbackHandle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const MaxString: u8 = 100;
+	pub const MaxCredentialsTypes: u8 = 50;
+	pub const MaxCredentialTypeLength: u32 = 32;
+	pub const MaxServices: u8 = 10;
+	pub const MaxHash: u32 = 512;
+	pub const DidDeposit: u64 = 5;
+	pub UniversalLocation: InteriorMultiLocation = Here;
+}
+
+impl pallet_did::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DidIdentifier = u64;
+	type AuthenticationAddress = H160;
+	type AssertionAddress = H160;
+	type DidDeposit = DidDeposit;
+	type MaxServices = MaxServices;
+	type MaxString = MaxString;
+	type MaxHash = MaxHash;
+	type MaxCredentialsTypes = MaxCredentialsTypes;
+	type MaxCredentialTypeLength = MaxCredentialTypeLength;
+	type GovernanceOrigin = EnsureRoot<u64>;
+	type CredentialQueryOrigin = NeverEnsureOrigin<MultiLocation>;
+	type XcmSender = ();
+	type UniversalLocation = UniversalLocation;
+	type XcmDidOrigin = NeverEnsureOrigin<MultiLocation>;
+	type LocationToAccountId = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DidBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DidBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_did::BenchmarkHelper<RuntimeOrigin> for DidBenchmarkHelper {
+	fn credential_querier() -> RuntimeOrigin {
+		RuntimeOrigin::root()
+	}
+}
+
+impl pallet_asset_policy::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PolicyOrigin = EnsureRoot<u64>;
+	type MaxAllowedCallers = ConstU32<4>;
+	type MaxTrustedIssuers = ConstU32<4>;
+	type WeightInfo = ();
+}
+
+/// Matches `GeneralIndex(asset_id)` to the asset of `pallet_assets` with that id.
+pub struct AssetsMatcher;
+impl MatchesFungibles<u32, u64> for AssetsMatcher {
+	fn matches_fungibles(asset: &MultiAsset) -> Result<(u32, u64), MatchError> {
+		match asset {
+			MultiAsset {
+				id: Concrete(MultiLocation { parents: 0, interior: X1(GeneralIndex(id)) }),
+				fun: Fungible(amount),
+			} => Ok((*id as u32, *amount as u64)),
+			_ => Err(MatchError::AssetNotHandled),
+		}
+	}
+}
+
+/// Converts `AccountIndex64` junctions to the account with that index.
+pub struct LocationToAccountId;
+impl ConvertLocation<u64> for LocationToAccountId {
+	fn convert_location(location: &MultiLocation) -> Option<u64> {
+		match location {
+			MultiLocation { parents: 0, interior: X1(AccountIndex64 { index, .. }) } => {
+				Some(*index)
+			},
+			_ => None,
+		}
+	}
+}
+
+/// Mints deposited assets of `pallet_assets`, like the fungibles transactor of the runtime.
+pub struct AssetsTransactor;
+impl TransactAsset for AssetsTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		let (asset_id, amount) =
+			AssetsMatcher::matches_fungibles(what).map_err(|_| XcmError::AssetNotFound)?;
+		let who = LocationToAccountId::convert_location(who)
+			.ok_or(XcmError::FailedToTransactAsset("AccountIdConversionFailed"))?;
+		Assets::mint_into(asset_id, &who, amount)
+			.map_err(|_| XcmError::FailedToTransactAsset("Mint failed"))?;
+		Ok(())
+	}
+}
+
+pub type CredentialedAssetsTransactor =
+	CredentialedDeposits<Test, AssetsTransactor, AssetsMatcher, LocationToAccountId>;
+
+pub(crate) const ISSUER: u64 = 1;
+pub(crate) const ALICE: u64 = 2;
+pub(crate) const BOB: u64 = 3;
+pub(crate) const CHARLIE: u64 = 4;
+pub(crate) const ASSET_ID: u32 = 1;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+		balances: pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(1, 10), (2, 20), (3, 30), (4, 40)],
+		},
+		asset_policy: Default::default(),
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,553 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use crate::types::PrecompileRestriction;
+use frame_support::{
+	assert_noop, assert_ok, bounded_vec,
+	dispatch::Dispatchable,
+	error::BadOrigin,
+	traits::{fungibles::Inspect, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use pallet_assets::FrozenBalance;
+use pallet_did::HashOf;
+use sp_core::H160;
+use sp_runtime::{traits::Get, DispatchError};
+use xcm::latest::prelude::*;
+
+fn events() -> Vec<Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(
+			|e| if let mock::RuntimeEvent::AssetPolicy(inner) = e { Some(inner) } else { None },
+		)
+		.collect::<Vec<_>>();
+
+	System::reset_events();
+
+	result
+}
+
+fn kyc() -> CredentialOf<Test> {
+	bounded_vec![b'k', b'y', b'c']
+}
+
+fn trusted() -> BoundedVec<u64, <Test as crate::Config>::MaxTrustedIssuers> {
+	bounded_vec![ISSUER]
+}
+
+fn create_did(who: u64) {
+	assert_ok!(DID::create_did(
+		RuntimeOrigin::signed(who),
+		who,
+		H160::from([0u8; 20]),
+		None,
+		BoundedVec::default()
+	));
+}
+
+/// Creates an asset with balances for `ALICE`, `BOB` and `CHARLIE`, and issues the `kyc`
+/// credential to `ALICE` and `BOB`.
+fn setup() {
+	let root = RuntimeOrigin::root();
+	let verifiable_credential_hash: HashOf<Test> = bounded_vec![1, 2, 3, 4, 5];
+
+	assert_ok!(Assets::force_create(root.clone(), ASSET_ID, ISSUER, true, 1));
+	for who in [ALICE, BOB, CHARLIE] {
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ISSUER), ASSET_ID, who, 100));
+	}
+
+	for who in [ISSUER, ALICE, BOB, CHARLIE] {
+		create_did(who);
+	}
+	assert_ok!(DID::add_credentials_type(root.clone(), bounded_vec![kyc()]));
+	assert_ok!(DID::add_issuer(root.clone(), ISSUER));
+	for who in [ALICE, BOB] {
+		assert_ok!(DID::issue_credentials(
+			RuntimeOrigin::signed(ISSUER),
+			ISSUER,
+			who,
+			bounded_vec![kyc()],
+			verifiable_credential_hash.clone()
+		));
+	}
+}
+
+fn transfer_call(to: u64, amount: u64) -> RuntimeCall {
+	RuntimeCall::Assets(pallet_assets::Call::transfer { id: ASSET_ID, target: to, amount })
+}
+
+fn xcm_asset(amount: u128) -> MultiAsset {
+	(GeneralIndex(ASSET_ID.into()), amount).into()
+}
+
+fn xcm_account(who: u64) -> MultiLocation {
+	AccountIndex64 { network: None, index: who }.into()
+}
+
+fn xcm_context() -> XcmContext {
+	XcmContext { origin: None, message_id: [0; 32], topic: None }
+}
+
+#[test]
+fn set_asset_credential_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DID::add_credentials_type(RuntimeOrigin::root(), bounded_vec![kyc()]));
+
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_eq!(AssetPolicy::asset_credential(ASSET_ID), Some(kyc()));
+		assert_eq!(AssetPolicy::trusted_issuers(ASSET_ID), trusted());
+		assert_eq!(
+			events(),
+			vec![Event::AssetCredentialSet {
+				asset_id: ASSET_ID,
+				credential: kyc(),
+				issuers: trusted()
+			}]
+		);
+	});
+}
+
+#[test]
+fn set_asset_credential_fails_if_not_governance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DID::add_credentials_type(RuntimeOrigin::root(), bounded_vec![kyc()]));
+
+		assert_noop!(
+			AssetPolicy::set_asset_credential(
+				RuntimeOrigin::signed(ALICE),
+				ASSET_ID,
+				kyc(),
+				trusted()
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_credential_fails_if_credential_type_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetPolicy::set_asset_credential(RuntimeOrigin::root(), ASSET_ID, kyc(), trusted()),
+			Error::<Test>::CredentialTypeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn remove_asset_credential_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DID::add_credentials_type(RuntimeOrigin::root(), bounded_vec![kyc()]));
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+		System::reset_events();
+
+		assert_noop!(
+			AssetPolicy::remove_asset_credential(RuntimeOrigin::signed(ALICE), ASSET_ID),
+			BadOrigin
+		);
+		assert_ok!(AssetPolicy::remove_asset_credential(RuntimeOrigin::root(), ASSET_ID));
+
+		assert_eq!(AssetPolicy::asset_credential(ASSET_ID), None);
+		assert!(AssetPolicy::trusted_issuers(ASSET_ID).is_empty());
+		assert_eq!(events(), vec![Event::AssetCredentialRemoved { asset_id: ASSET_ID }]);
+		assert_noop!(
+			AssetPolicy::remove_asset_credential(RuntimeOrigin::root(), ASSET_ID),
+			Error::<Test>::AssetCredentialNotSet
+		);
+	});
+}
+
+#[test]
+fn transfers_are_unrestricted_without_policy() {
+	new_test_ext().execute_with(|| {
+		setup();
+
+		assert_ok!(transfer_call(ALICE, 10).dispatch(RuntimeOrigin::signed(CHARLIE)));
+		assert_ok!(transfer_call(CHARLIE, 10).dispatch(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 100);
+	});
+}
+
+#[test]
+fn transfers_between_credentialed_accounts_work() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_ok!(transfer_call(BOB, 10).dispatch(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 90);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 110);
+	});
+}
+
+#[test]
+fn transfers_from_non_credentialed_accounts_fail() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(CHARLIE), ASSET_ID, ALICE, 10),
+			pallet_assets::Error::<Test>::BalanceLow
+		);
+		assert_eq!(AssetPolicy::frozen_balance(ASSET_ID, &CHARLIE), Some(100));
+		assert_eq!(AssetPolicy::frozen_balance(ASSET_ID, &ALICE), None);
+	});
+}
+
+#[test]
+fn transfers_to_non_credentialed_accounts_are_filtered() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_noop!(
+			transfer_call(CHARLIE, 10).dispatch(RuntimeOrigin::signed(ALICE)),
+			DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+		);
+		assert_noop!(
+			RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+				id: ASSET_ID,
+				target: CHARLIE,
+				amount: 10
+			})
+			.dispatch(RuntimeOrigin::signed(ALICE)),
+			DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+		);
+
+		// Approved transfers are checked against the destination
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(ALICE), ASSET_ID, BOB, 10));
+		assert_noop!(
+			RuntimeCall::Assets(pallet_assets::Call::transfer_approved {
+				id: ASSET_ID,
+				owner: ALICE,
+				destination: CHARLIE,
+				amount: 10
+			})
+			.dispatch(RuntimeOrigin::signed(BOB)),
+			DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+		);
+		assert_ok!(RuntimeCall::Assets(pallet_assets::Call::transfer_approved {
+			id: ASSET_ID,
+			owner: ALICE,
+			destination: BOB,
+			amount: 10
+		})
+		.dispatch(RuntimeOrigin::signed(BOB)));
+	});
+}
+
+#[test]
+fn mints_to_non_credentialed_accounts_are_filtered() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_noop!(
+			RuntimeCall::Assets(pallet_assets::Call::mint {
+				id: ASSET_ID,
+				beneficiary: CHARLIE,
+				amount: 10
+			})
+			.dispatch(RuntimeOrigin::signed(ISSUER)),
+			DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+		);
+		assert_ok!(RuntimeCall::Assets(pallet_assets::Call::mint {
+			id: ASSET_ID,
+			beneficiary: ALICE,
+			amount: 10
+		})
+		.dispatch(RuntimeOrigin::signed(ISSUER)));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 110);
+	});
+}
+
+#[test]
+fn xcm_deposits_to_non_credentialed_accounts_fail() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_eq!(
+			CredentialedAssetsTransactor::deposit_asset(
+				&xcm_asset(10),
+				&xcm_account(CHARLIE),
+				&xcm_context()
+			),
+			Err(XcmError::FailedToTransactAsset("Destination is not credentialed"))
+		);
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 100);
+
+		assert_ok!(CredentialedAssetsTransactor::deposit_asset(
+			&xcm_asset(10),
+			&xcm_account(ALICE),
+			&xcm_context()
+		));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 110);
+	});
+}
+
+#[test]
+fn xcm_deposits_are_unrestricted_without_policy() {
+	new_test_ext().execute_with(|| {
+		setup();
+
+		assert_ok!(CredentialedAssetsTransactor::deposit_asset(
+			&xcm_asset(10),
+			&xcm_account(CHARLIE),
+			&xcm_context()
+		));
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 110);
+	});
+}
+
+#[test]
+fn xcm_transfers_to_non_credentialed_accounts_fail() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert_eq!(
+			CredentialedAssetsTransactor::transfer_asset(
+				&xcm_asset(10),
+				&xcm_account(ALICE),
+				&xcm_account(CHARLIE),
+				&xcm_context()
+			),
+			Err(XcmError::FailedToTransactAsset("Destination is not credentialed"))
+		);
+	});
+}
+
+#[test]
+fn revoking_issuer_blocks_transfers() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+		assert!(AssetPolicy::is_credentialed(ASSET_ID, &ALICE));
+
+		assert_ok!(DID::revoke_issuer(RuntimeOrigin::root(), ISSUER));
+
+		assert!(!AssetPolicy::is_credentialed(ASSET_ID, &ALICE));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(ALICE), ASSET_ID, BOB, 10),
+			pallet_assets::Error::<Test>::BalanceLow
+		);
+	});
+}
+
+#[test]
+fn credentials_from_untrusted_issuers_are_ignored() {
+	new_test_ext().execute_with(|| {
+		setup();
+		// `CHARLIE` is an active issuer, but not trusted for the asset
+		assert_ok!(DID::add_issuer(RuntimeOrigin::root(), CHARLIE));
+		assert_ok!(DID::issue_credentials(
+			RuntimeOrigin::signed(CHARLIE),
+			CHARLIE,
+			CHARLIE,
+			bounded_vec![kyc()],
+			bounded_vec![1, 2, 3]
+		));
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			trusted()
+		));
+
+		assert!(!AssetPolicy::is_credentialed(ASSET_ID, &CHARLIE));
+		assert_noop!(
+			transfer_call(CHARLIE, 10).dispatch(RuntimeOrigin::signed(ALICE)),
+			DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+		);
+
+		assert_ok!(AssetPolicy::set_asset_credential(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			kyc(),
+			bounded_vec![ISSUER, CHARLIE]
+		));
+		assert!(AssetPolicy::is_credentialed(ASSET_ID, &CHARLIE));
+		assert_ok!(transfer_call(CHARLIE, 10).dispatch(RuntimeOrigin::signed(ALICE)));
+	});
+}
+
+#[test]
+fn set_precompile_restriction_works() {
+	new_test_ext().execute_with(|| {
+		let restriction = PrecompileRestriction::Allowlist(bounded_vec![H160::repeat_byte(0xAA)]);
+
+		assert_noop!(
+			AssetPolicy::set_precompile_restriction(
+				RuntimeOrigin::signed(ALICE),
+				ASSET_ID,
+				PrecompileRestriction::IssuerOnly
+			),
+			BadOrigin
+		);
+		assert_ok!(AssetPolicy::set_precompile_restriction(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			PrecompileRestriction::IssuerOnly
+		));
+		assert_ok!(AssetPolicy::set_precompile_restriction(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			restriction.clone()
+		));
+
+		assert_eq!(AssetPolicy::precompile_restriction(ASSET_ID), Some(restriction.clone()));
+		assert_eq!(
+			events(),
+			vec![
+				Event::PrecompileRestrictionSet {
+					asset_id: ASSET_ID,
+					restriction: PrecompileRestriction::IssuerOnly
+				},
+				Event::PrecompileRestrictionSet { asset_id: ASSET_ID, restriction },
+			]
+		);
+	});
+}
+
+#[test]
+fn remove_precompile_restriction_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetPolicy::remove_precompile_restriction(RuntimeOrigin::root(), ASSET_ID),
+			Error::<Test>::PrecompileRestrictionNotSet
+		);
+		assert_ok!(AssetPolicy::set_precompile_restriction(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			PrecompileRestriction::IssuerOnly
+		));
+		System::reset_events();
+
+		assert_noop!(
+			AssetPolicy::remove_precompile_restriction(RuntimeOrigin::signed(ALICE), ASSET_ID),
+			BadOrigin
+		);
+		assert_ok!(AssetPolicy::remove_precompile_restriction(RuntimeOrigin::root(), ASSET_ID));
+
+		assert_eq!(AssetPolicy::precompile_restriction(ASSET_ID), None);
+		assert_eq!(events(), vec![Event::PrecompileRestrictionRemoved { asset_id: ASSET_ID }]);
+	});
+}
+
+#[test]
+fn is_precompile_caller_allowed_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let contract = H160::repeat_byte(0xAA);
+		let other = H160::repeat_byte(0xBB);
+
+		// Unrestricted precompiles can be called by anyone
+		assert!(AssetPolicy::is_precompile_caller_allowed(ASSET_ID, other, &ALICE));
+
+		assert_ok!(AssetPolicy::set_precompile_restriction(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			PrecompileRestriction::IssuerOnly
+		));
+		assert!(AssetPolicy::is_precompile_caller_allowed(ASSET_ID, other, &ISSUER));
+		assert!(!AssetPolicy::is_precompile_caller_allowed(ASSET_ID, other, &ALICE));
+
+		assert_ok!(AssetPolicy::set_precompile_restriction(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			PrecompileRestriction::Allowlist(bounded_vec![contract])
+		));
+		assert!(AssetPolicy::is_precompile_caller_allowed(ASSET_ID, contract, &ALICE));
+		assert!(!AssetPolicy::is_precompile_caller_allowed(ASSET_ID, other, &ISSUER));
+	});
+}
+
+#[test]
+fn migrate_to_v1_restricts_issuer_only_assets() {
+	new_test_ext().execute_with(|| {
+		frame_support::parameter_types! {
+			pub IssuerOnlyAssets: Vec<u32> = vec![ASSET_ID, ASSET_ID + 1];
+		}
+		StorageVersion::new(0).put::<AssetPolicy>();
+
+		migrations::v1::MigrateToV1::<Test, IssuerOnlyAssets>::on_runtime_upgrade();
+
+		assert_eq!(AssetPolicy::on_chain_storage_version(), 1);
+		for asset_id in IssuerOnlyAssets::get() {
+			assert_eq!(
+				AssetPolicy::precompile_restriction(asset_id),
+				Some(PrecompileRestriction::IssuerOnly)
+			);
+		}
+
+		// Running the migration again is a no-op
+		assert_ok!(AssetPolicy::remove_precompile_restriction(RuntimeOrigin::root(), ASSET_ID));
+		migrations::v1::MigrateToV1::<Test, IssuerOnlyAssets>::on_runtime_upgrade();
+		assert_eq!(AssetPolicy::precompile_restriction(ASSET_ID), None);
+	});
+}
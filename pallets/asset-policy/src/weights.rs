@@ -0,0 +1,124 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_asset_policy
+//!
+//! These are conservative estimates and must be replaced by running
+//! the `pallet_asset_policy` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_policy.
+pub trait WeightInfo {
+	fn set_asset_credential(i: u32, ) -> Weight;
+	fn remove_asset_credential() -> Weight;
+	fn set_precompile_restriction(c: u32, ) -> Weight;
+	fn remove_precompile_restriction() -> Weight;
+}
+
+/// Weights for pallet_asset_policy using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DID CredentialsTypes (r:1 w:0)
+	/// Proof: DID CredentialsTypes (max_values: Some(1), max_size: Some(1651), added: 2146, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy AssetCredentials (r:0 w:1)
+	/// Proof: AssetPolicy AssetCredentials (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy TrustedIssuers (r:0 w:1)
+	/// Proof: AssetPolicy TrustedIssuers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 16]`.
+	fn set_asset_credential(i: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3136))
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetPolicy AssetCredentials (r:1 w:1)
+	/// Proof: AssetPolicy AssetCredentials (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy TrustedIssuers (r:0 w:1)
+	/// Proof: AssetPolicy TrustedIssuers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
+	fn remove_asset_credential() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3518))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetPolicy PrecompileRestrictions (r:0 w:1)
+	/// Proof: AssetPolicy PrecompileRestrictions (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 16]`.
+	fn set_precompile_restriction(c: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetPolicy PrecompileRestrictions (r:1 w:1)
+	/// Proof: AssetPolicy PrecompileRestrictions (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	fn remove_precompile_restriction() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3820))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DID CredentialsTypes (r:1 w:0)
+	/// Proof: DID CredentialsTypes (max_values: Some(1), max_size: Some(1651), added: 2146, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy AssetCredentials (r:0 w:1)
+	/// Proof: AssetPolicy AssetCredentials (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy TrustedIssuers (r:0 w:1)
+	/// Proof: AssetPolicy TrustedIssuers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 16]`.
+	fn set_asset_credential(i: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3136))
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: AssetPolicy AssetCredentials (r:1 w:1)
+	/// Proof: AssetPolicy AssetCredentials (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: AssetPolicy TrustedIssuers (r:0 w:1)
+	/// Proof: AssetPolicy TrustedIssuers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
+	fn remove_asset_credential() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3518))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: AssetPolicy PrecompileRestrictions (r:0 w:1)
+	/// Proof: AssetPolicy PrecompileRestrictions (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 16]`.
+	fn set_precompile_restriction(c: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: AssetPolicy PrecompileRestrictions (r:1 w:1)
+	/// Proof: AssetPolicy PrecompileRestrictions (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	fn remove_precompile_restriction() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3820))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,76 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::{assert_ok, traits::Get};
+use sp_std::vec;
+
+use super::Pallet as CircuitBreaker;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn name<T: Config>(byte: u8) -> NameOf<T> {
+	NameOf::<T>::truncate_from(vec![byte; T::MaxNameLength::get() as usize])
+}
+
+benchmarks! {
+	pause_pallet {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let pallet_name = name::<T>(b'p');
+	}: _<T::RuntimeOrigin>(origin, pallet_name.clone())
+	verify {
+		assert!(PausedPallets::<T>::contains_key(&pallet_name));
+		assert_last_event::<T>(Event::PalletPaused { pallet_name }.into());
+	}
+
+	unpause_pallet {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let pallet_name = name::<T>(b'p');
+		assert_ok!(CircuitBreaker::<T>::pause_pallet(origin.clone(), pallet_name.clone()));
+	}: _<T::RuntimeOrigin>(origin, pallet_name.clone())
+	verify {
+		assert!(!PausedPallets::<T>::contains_key(&pallet_name));
+		assert_last_event::<T>(Event::PalletUnpaused { pallet_name }.into());
+	}
+
+	pause_call {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let pallet_name = name::<T>(b'p');
+		let call_name = name::<T>(b'c');
+	}: _<T::RuntimeOrigin>(origin, pallet_name.clone(), call_name.clone())
+	verify {
+		assert!(PausedCalls::<T>::contains_key(&pallet_name, &call_name));
+		assert_last_event::<T>(Event::CallPaused { pallet_name, call_name }.into());
+	}
+
+	unpause_call {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let pallet_name = name::<T>(b'p');
+		let call_name = name::<T>(b'c');
+		assert_ok!(CircuitBreaker::<T>::pause_call(
+			origin.clone(),
+			pallet_name.clone(),
+			call_name.clone()
+		));
+	}: _<T::RuntimeOrigin>(origin, pallet_name.clone(), call_name.clone())
+	verify {
+		assert!(!PausedCalls::<T>::contains_key(&pallet_name, &call_name));
+		assert_last_event::<T>(Event::CallUnpaused { pallet_name, call_name }.into());
+	}
+}
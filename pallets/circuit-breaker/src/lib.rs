@@ -0,0 +1,224 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Circuit Breaker Pallet
+//!
+//! Lets governance pause whole pallets or individual calls during an incident, and resume them
+//! once it is over, without a runtime upgrade.
+//!
+//! Pauses are enforced by using [`NotPaused`] as (part of) the runtime `BaseCallFilter`. Calls
+//! of this pallet and calls matched by `Config::UnpausableCalls`, such as governance calls, are
+//! never paused. Pallets and calls are identified by the names of their `CallMetadata`, e.g.
+//! `DID` and `issue_credentials`.
+//!
+//! The filter does not know the origin of a call. Calls dispatched by a collective origin, such as
+//! a council proposal, are filtered like any other; only `Root` and calls dispatched bypassing the
+//! filter, as motions do, reach a paused pallet. Remediating an incident in a paused pallet must
+//! therefore go through a motion.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use frame_support::{
+	traits::{CallMetadata, Contains, EnsureOrigin, GetCallMetadata, PalletInfoAccess},
+	BoundedVec,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Name of a pallet or of a call.
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to pause and unpause pallets and calls.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Calls that are never paused, such as governance calls.
+		type UnpausableCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The maximum length of a pallet or call name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Pallets whose calls are all paused.
+	#[pallet::storage]
+	#[pallet::getter(fn paused_pallet)]
+	pub type PausedPallets<T: Config> = StorageMap<_, Blake2_128Concat, NameOf<T>, ()>;
+
+	/// Paused calls, by pallet name and call name.
+	#[pallet::storage]
+	#[pallet::getter(fn paused_call)]
+	pub type PausedCalls<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, NameOf<T>, Blake2_128Concat, NameOf<T>, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// All the calls of `pallet_name` are paused.
+		PalletPaused { pallet_name: NameOf<T> },
+		/// The calls of `pallet_name` are no longer paused as a whole.
+		PalletUnpaused { pallet_name: NameOf<T> },
+		/// The call `call_name` of `pallet_name` is paused.
+		CallPaused { pallet_name: NameOf<T>, call_name: NameOf<T> },
+		/// The call `call_name` of `pallet_name` is no longer paused.
+		CallUnpaused { pallet_name: NameOf<T>, call_name: NameOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The calls of this pallet can not be paused
+		CannotPause,
+		/// The pallet or call is already paused
+		AlreadyPaused,
+		/// The pallet or call is not paused
+		NotPaused,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause all the calls of `pallet_name`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::pause_pallet())]
+		pub fn pause_pallet(origin: OriginFor<T>, pallet_name: NameOf<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_own_pallet(&pallet_name), Error::<T>::CannotPause);
+			ensure!(!PausedPallets::<T>::contains_key(&pallet_name), Error::<T>::AlreadyPaused);
+
+			PausedPallets::<T>::insert(&pallet_name, ());
+			Self::deposit_event(Event::PalletPaused { pallet_name });
+			Ok(())
+		}
+
+		/// Resume the calls of `pallet_name`, except the calls paused individually.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unpause_pallet())]
+		pub fn unpause_pallet(origin: OriginFor<T>, pallet_name: NameOf<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(PausedPallets::<T>::contains_key(&pallet_name), Error::<T>::NotPaused);
+
+			PausedPallets::<T>::remove(&pallet_name);
+			Self::deposit_event(Event::PalletUnpaused { pallet_name });
+			Ok(())
+		}
+
+		/// Pause the call `call_name` of `pallet_name`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_call())]
+		pub fn pause_call(
+			origin: OriginFor<T>,
+			pallet_name: NameOf<T>,
+			call_name: NameOf<T>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_own_pallet(&pallet_name), Error::<T>::CannotPause);
+			ensure!(
+				!PausedCalls::<T>::contains_key(&pallet_name, &call_name),
+				Error::<T>::AlreadyPaused
+			);
+
+			PausedCalls::<T>::insert(&pallet_name, &call_name, ());
+			Self::deposit_event(Event::CallPaused { pallet_name, call_name });
+			Ok(())
+		}
+
+		/// Resume the call `call_name` of `pallet_name`, unless its whole pallet is paused.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause_call())]
+		pub fn unpause_call(
+			origin: OriginFor<T>,
+			pallet_name: NameOf<T>,
+			call_name: NameOf<T>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(
+				PausedCalls::<T>::contains_key(&pallet_name, &call_name),
+				Error::<T>::NotPaused
+			);
+
+			PausedCalls::<T>::remove(&pallet_name, &call_name);
+			Self::deposit_event(Event::CallUnpaused { pallet_name, call_name });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if the call `call_name` of `pallet_name` is paused, on its own or with its
+	/// whole pallet.
+	pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+		// Names longer than `MaxNameLength` can not be paused
+		let pallet_name = match NameOf::<T>::try_from(pallet_name.to_vec()) {
+			Ok(pallet_name) => pallet_name,
+			Err(_) => return false,
+		};
+		if PausedPallets::<T>::contains_key(&pallet_name) {
+			return true;
+		}
+		match NameOf::<T>::try_from(call_name.to_vec()) {
+			Ok(call_name) => PausedCalls::<T>::contains_key(&pallet_name, &call_name),
+			Err(_) => false,
+		}
+	}
+
+	fn is_own_pallet(pallet_name: &[u8]) -> bool {
+		pallet_name == <Self as PalletInfoAccess>::name().as_bytes()
+	}
+}
+
+/// Call filter rejecting the calls of paused pallets and paused calls.
+pub struct NotPaused<T>(PhantomData<T>);
+
+impl<T> Contains<<T as frame_system::Config>::RuntimeCall> for NotPaused<T>
+where
+	T: Config,
+	<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+{
+	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		if T::UnpausableCalls::contains(call) {
+			return true;
+		}
+
+		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+		Pallet::<T>::is_own_pallet(pallet_name.as_bytes())
+			|| !Pallet::<T>::is_paused(pallet_name.as_bytes(), function_name.as_bytes())
+	}
+}
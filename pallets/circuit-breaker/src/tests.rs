@@ -0,0 +1,249 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, error::BadOrigin, BoundedVec};
+use frame_system::Call as SystemCall;
+use sp_runtime::DispatchError;
+
+fn name(name: &str) -> NameOf<Test> {
+	BoundedVec::truncate_from(name.as_bytes().to_vec())
+}
+
+fn transfer() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 2, value: 1 })
+}
+
+fn transfer_keep_alive() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { dest: 2, value: 1 })
+}
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(SystemCall::remark { remark: vec![] })
+}
+
+fn call_filtered() -> DispatchError {
+	DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+}
+
+#[test]
+fn pause_pallet_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("Balances")));
+
+		assert_eq!(CircuitBreaker::paused_pallet(name("Balances")), Some(()));
+		System::assert_last_event(Event::PalletPaused { pallet_name: name("Balances") }.into());
+		assert_noop!(transfer().dispatch(RuntimeOrigin::signed(1)), call_filtered());
+		assert_noop!(transfer_keep_alive().dispatch(RuntimeOrigin::signed(1)), call_filtered());
+		assert_eq!(Balances::free_balance(2), 20);
+	});
+}
+
+#[test]
+fn unpause_pallet_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("Balances")));
+		assert_ok!(CircuitBreaker::unpause_pallet(RuntimeOrigin::root(), name("Balances")));
+
+		assert_eq!(CircuitBreaker::paused_pallet(name("Balances")), None);
+		System::assert_last_event(Event::PalletUnpaused { pallet_name: name("Balances") }.into());
+		assert_ok!(transfer().dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 21);
+	});
+}
+
+#[test]
+fn pause_call_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+
+		System::assert_last_event(
+			Event::CallPaused {
+				pallet_name: name("Balances"),
+				call_name: name("transfer_allow_death"),
+			}
+			.into(),
+		);
+		assert_noop!(transfer().dispatch(RuntimeOrigin::signed(1)), call_filtered());
+		// Other calls of the pallet are not paused
+		assert_ok!(transfer_keep_alive().dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 21);
+	});
+}
+
+#[test]
+fn unpause_call_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+		assert_ok!(CircuitBreaker::unpause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+
+		assert_eq!(
+			CircuitBreaker::paused_call(name("Balances"), name("transfer_allow_death")),
+			None
+		);
+		assert_ok!(transfer().dispatch(RuntimeOrigin::signed(1)));
+	});
+}
+
+#[test]
+fn unpausing_a_call_keeps_its_pallet_paused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("Balances")));
+		assert_ok!(CircuitBreaker::pause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+		assert_ok!(CircuitBreaker::unpause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+
+		assert_noop!(transfer().dispatch(RuntimeOrigin::signed(1)), call_filtered());
+	});
+}
+
+#[test]
+fn unpausable_calls_are_never_paused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("System")));
+		assert_ok!(CircuitBreaker::pause_call(
+			RuntimeOrigin::root(),
+			name("System"),
+			name("remark")
+		));
+
+		assert_ok!(remark().dispatch(RuntimeOrigin::signed(1)));
+	});
+}
+
+#[test]
+fn circuit_breaker_can_not_be_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("CircuitBreaker")),
+			Error::<Test>::CannotPause
+		);
+		assert_noop!(
+			CircuitBreaker::pause_call(
+				RuntimeOrigin::root(),
+				name("CircuitBreaker"),
+				name("unpause_pallet")
+			),
+			Error::<Test>::CannotPause
+		);
+	});
+}
+
+#[test]
+fn pause_fails_if_already_paused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("Balances")));
+		assert_noop!(
+			CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("Balances")),
+			Error::<Test>::AlreadyPaused
+		);
+
+		assert_ok!(CircuitBreaker::pause_call(
+			RuntimeOrigin::root(),
+			name("Balances"),
+			name("transfer_allow_death")
+		));
+		assert_noop!(
+			CircuitBreaker::pause_call(
+				RuntimeOrigin::root(),
+				name("Balances"),
+				name("transfer_allow_death")
+			),
+			Error::<Test>::AlreadyPaused
+		);
+	});
+}
+
+#[test]
+fn unpause_fails_if_not_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::unpause_pallet(RuntimeOrigin::root(), name("Balances")),
+			Error::<Test>::NotPaused
+		);
+		assert_noop!(
+			CircuitBreaker::unpause_call(
+				RuntimeOrigin::root(),
+				name("Balances"),
+				name("transfer_allow_death")
+			),
+			Error::<Test>::NotPaused
+		);
+	});
+}
+
+#[test]
+fn only_pause_origin_can_pause() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::pause_pallet(RuntimeOrigin::signed(1), name("Balances")),
+			BadOrigin
+		);
+		assert_noop!(
+			CircuitBreaker::pause_call(
+				RuntimeOrigin::signed(1),
+				name("Balances"),
+				name("transfer_allow_death")
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CircuitBreaker::unpause_pallet(RuntimeOrigin::signed(1), name("Balances")),
+			BadOrigin
+		);
+		assert_noop!(
+			CircuitBreaker::unpause_call(
+				RuntimeOrigin::signed(1),
+				name("Balances"),
+				name("transfer_allow_death")
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn names_are_matched_exactly() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::pause_pallet(RuntimeOrigin::root(), name("balances")));
+
+		assert!(!CircuitBreaker::is_paused(b"Balances", b"transfer_allow_death"));
+		assert!(CircuitBreaker::is_paused(b"balances", b"transfer_allow_death"));
+		// Names longer than `MaxNameLength` can not be paused
+		assert!(!CircuitBreaker::is_paused(&[b'a'; 33], b"transfer_allow_death"));
+		assert_ok!(transfer().dispatch(RuntimeOrigin::signed(1)));
+	});
+}
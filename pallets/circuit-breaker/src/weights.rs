@@ -0,0 +1,108 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_circuit_breaker
+//!
+//! These are conservative estimates and must be replaced by running
+//! the `pallet_circuit_breaker` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_circuit_breaker.
+pub trait WeightInfo {
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+	fn pause_call() -> Weight;
+	fn unpause_call() -> Weight;
+}
+
+/// Weights for pallet_circuit_breaker using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: CircuitBreaker PausedPallets (r:1 w:1)
+	/// Proof: CircuitBreaker PausedPallets (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn pause_pallet() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedPallets (r:1 w:1)
+	/// Proof: CircuitBreaker PausedPallets (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn unpause_pallet() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedCalls (r:1 w:1)
+	/// Proof: CircuitBreaker PausedCalls (max_values: None, max_size: Some(162), added: 2637, mode: MaxEncodedLen)
+	fn pause_call() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3627))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedCalls (r:1 w:1)
+	/// Proof: CircuitBreaker PausedCalls (max_values: None, max_size: Some(162), added: 2637, mode: MaxEncodedLen)
+	fn unpause_call() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3627))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: CircuitBreaker PausedPallets (r:1 w:1)
+	/// Proof: CircuitBreaker PausedPallets (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn pause_pallet() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedPallets (r:1 w:1)
+	/// Proof: CircuitBreaker PausedPallets (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn unpause_pallet() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedCalls (r:1 w:1)
+	/// Proof: CircuitBreaker PausedCalls (max_values: None, max_size: Some(162), added: 2637, mode: MaxEncodedLen)
+	fn pause_call() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3627))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: CircuitBreaker PausedCalls (r:1 w:1)
+	/// Proof: CircuitBreaker PausedCalls (max_values: None, max_size: Some(162), added: 2637, mode: MaxEncodedLen)
+	fn unpause_call() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3627))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
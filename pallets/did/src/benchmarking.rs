@@ -15,11 +15,14 @@
 // along with Watr.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
-use frame_support::assert_ok;
+use frame_benchmarking::{
+	account, benchmarks, whitelist_account, whitelisted_caller, BenchmarkError,
+};
+use frame_support::{assert_ok, weights::Weight};
 use frame_system::RawOrigin;
 use sp_core::H160;
 use sp_runtime::traits::Bounded;
+use xcm::latest::prelude::*;
 
 use super::{types::ServiceType, Pallet as DID};
 
@@ -558,4 +561,91 @@ benchmarks! {
 		assert_eq!(CredentialsTypes::<T>::get(), Vec::default());
 		assert_last_event::<T>(Event::CredentialTypesRemoved {credentials: credentials_types}.into());
 	}
+
+	add_credential_querier {
+		let querier = MultiLocation::new(1, X1(Parachain(2000)));
+		let fee_asset = MultiLocation::parent();
+	}: _(
+		RawOrigin::Root, Box::new(querier.into()), [50, 3], Box::new(fee_asset.into()), 1_000
+	)
+	verify {
+		assert!(CredentialQueriers::<T>::contains_key(querier));
+		let info = CredentialQuerierInfo { response_call: [50, 3], fee_asset, fee_amount: 1_000 };
+		assert_last_event::<T>(Event::CredentialQuerierAdded { querier, info }.into());
+	}
+
+	remove_credential_querier {
+		let querier = MultiLocation::new(1, X1(Parachain(2000)));
+		assert_ok!(DID::<T>::add_credential_querier(RawOrigin::Root.into(), Box::new(querier.into()), [50, 3], Box::new(MultiLocation::parent().into()), 1_000));
+	}: _(
+		RawOrigin::Root, Box::new(querier.into())
+	)
+	verify {
+		assert!(!CredentialQueriers::<T>::contains_key(querier));
+		assert_last_event::<T>(Event::CredentialQuerierRemoved { querier }.into());
+	}
+
+	query_credential {
+		let root: T::RuntimeOrigin = RawOrigin::Root.into();
+
+		// Dependancy - Create the subject and issuer DIDs
+		let (services, services_keys) = create_services::<T>(0, 1);
+		let document: Document<T> = create_did_document(1, 1, 1, &services_keys);
+		let did: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&did, BalanceOf::<T>::max_value());
+		assert_ok!(DID::create_did(
+			RawOrigin::Signed(did.clone()).into(),
+			document.clone().controller,
+			document.clone().authentication.controller,
+			Some(document.clone().assertion_method.unwrap().controller),
+			services
+		));
+
+		let (services, services_keys) = create_services::<T>(0, 1);
+		let document: Document<T> = create_did_document(2, 2, 2, &services_keys);
+		let issuer_did: T::AccountId = issuer::<T>(2).into();
+		T::Currency::make_free_balance_be(&issuer_did, BalanceOf::<T>::max_value());
+		assert_ok!(DID::create_did(
+			RawOrigin::Signed(issuer_did.clone()).into(),
+			document.clone().controller,
+			document.clone().authentication.controller,
+			Some(document.clone().assertion_method.unwrap().controller),
+			services
+		));
+		assert_ok!(DID::<T>::add_issuer(root.clone(), T::DidIdentifier::from(issuer_did.clone())));
+
+		let controller: T::AccountId = controller::<T>(2).into();
+		let credentials = create_credentials::<T>(1, 1);
+		let mut verifiable_credential_hash: HashOf<T> = HashOf::<T>::default();
+		for i in 0..T::MaxHash::get() {
+			let _ = verifiable_credential_hash.try_push(i as u8);
+		}
+		assert_ok!(DID::<T>::add_credentials_type(root.clone(), credentials.clone()));
+		assert_ok!(DID::<T>::issue_credentials(
+			RawOrigin::Signed(controller).into(),
+			T::DidIdentifier::from(issuer_did.clone()),
+			T::DidIdentifier::from(did.clone()),
+			credentials.clone(),
+			verifiable_credential_hash.clone()
+		));
+
+		let querier_origin = T::BenchmarkHelper::credential_querier();
+		let querier = T::CredentialQueryOrigin::ensure_origin(querier_origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		assert_ok!(DID::<T>::add_credential_querier(root, Box::new(querier.into()), [50, 3], Box::new(MultiLocation::parent().into()), 1_000));
+		let credential = credentials[0].clone();
+		let issuer = T::DidIdentifier::from(issuer_did);
+	}: _<T::RuntimeOrigin>(
+		querier_origin, 0, T::DidIdentifier::from(did.clone()), credential.clone(), issuer.clone(), Weight::zero()
+	)
+	verify {
+		assert_last_event::<T>(Event::CredentialQueried {
+			querier,
+			query_id: 0,
+			did: T::DidIdentifier::from(did),
+			credential,
+			issuer,
+			verifiable_credential_hash: Some(verifiable_credential_hash),
+		}.into());
+	}
 }
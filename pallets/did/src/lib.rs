@@ -27,8 +27,8 @@ mod benchmarking;
 pub mod types;
 
 use crate::types::{
-	AssertionMethod, AuthenticationMethod, CredentialInfo, Document, IssuerInfo, IssuerStatus,
-	Service, ServiceInfo, ServicesWitness,
+	AssertionMethod, AuthenticationMethod, CredentialInfo, CredentialQuerierInfo, Document,
+	IssuerInfo, IssuerStatus, Service, ServiceInfo, ServicesWitness,
 };
 use frame_support::{
 	dispatch::DispatchResult,
@@ -39,9 +39,20 @@ use frame_support::{
 	BoundedVec, Parameter,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use parity_scale_codec::Encode;
 use sp_core::{H160, H256};
 use sp_runtime::{traits::Hash, ArithmeticError};
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
+use xcm::{
+	latest::{
+		send_xcm,
+		Instruction::{BuyExecution, DepositAsset, RefundSurplus, Transact, WithdrawAsset},
+		InteriorMultiLocation, MultiAsset, MultiLocation, OriginKind, QueryId, SendXcm,
+		WeightLimit, WildMultiAsset, Xcm,
+	},
+	VersionedMultiLocation,
+};
+use xcm_executor::traits::ConvertLocation;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -69,6 +80,10 @@ pub mod pallet {
 	/// Type for a Service key identifier.
 	pub type KeyIdOf<T> = <T as frame_system::Config>::Hash;
 
+	/// Pallet and call index, on the chain of a credential querier, of the call answering its
+	/// credential queries.
+	pub type ResponseCallIndex = [u8; 2];
+
 	/// Type for Watr account identifier.
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -136,8 +151,27 @@ pub mod pallet {
 		/// Origin for privileged actions
 		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin of cross-chain credential queries, resolving to the location of the querier.
+		type CredentialQueryOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Sends the responses to credential queries.
+		type XcmSender: SendXcm;
+
+		/// Location of this chain, to refund the unused fees of the responses to.
+		type UniversalLocation: Get<InteriorMultiLocation>;
+
+		/// Origin of XCM locations managing their own DID, resolving to the location.
+		type XcmDidOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Converts the location of an XCM origin to the account its DID is created for.
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// Helper to set up the credential query benchmark.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::RuntimeOrigin>;
 	}
 
 	/// DID Resolver
@@ -171,6 +205,18 @@ pub mod pallet {
 		CredentialInfo<T>,
 	>;
 
+	/// Locations of the DIDs created from XCM origins.
+	#[pallet::storage]
+	#[pallet::getter(fn did_locations)]
+	pub type DidLocations<T: Config> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, MultiLocation>;
+
+	/// Locations allowed to query credentials over XCM, with how they receive the answers.
+	#[pallet::storage]
+	#[pallet::getter(fn credential_queriers)]
+	pub type CredentialQueriers<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, CredentialQuerierInfo>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -234,6 +280,21 @@ pub mod pallet {
 		IssuerStatusRevoked {
 			issuer: DidIdentifierOf<T>,
 		},
+		CredentialQuerierAdded {
+			querier: MultiLocation,
+			info: CredentialQuerierInfo,
+		},
+		CredentialQuerierRemoved {
+			querier: MultiLocation,
+		},
+		CredentialQueried {
+			querier: MultiLocation,
+			query_id: QueryId,
+			did: DidIdentifierOf<T>,
+			credential: CredentialOf<T>,
+			issuer: DidIdentifierOf<T>,
+			verifiable_credential_hash: Option<HashOf<T>>,
+		},
 	}
 
 	#[pallet::error]
@@ -274,6 +335,18 @@ pub mod pallet {
 		TooManyServiceConsumers,
 		/// The maximum number of Services in the DID has been exceeded
 		TooManyServicesInDid,
+		/// The location is not allowed to query credentials
+		NotCredentialQuerier,
+		/// Unable to add a credential querier that already exists
+		CredentialQuerierAlreadyAdded,
+		/// Unable to find credential querier
+		CredentialQuerierDoesNotExist,
+		/// The location could not be converted to the latest XCM version
+		BadVersion,
+		/// The response to a credential query could not be sent
+		CredentialQueryResponseFailed,
+		/// The location of the XCM origin could not be converted to an account
+		LocationNotConvertible,
 	}
 
 	#[pallet::call]
@@ -287,7 +360,7 @@ pub mod pallet {
 			assertion: Option<T::AssertionAddress>,
 			services: BoundedVec<ServiceInfo<T>, T::MaxServices>,
 		) -> DispatchResultWithPostInfo {
-			let origin = ensure_signed(origin)?;
+			let (origin, location) = Self::ensure_did_origin(origin)?;
 			let did = T::DidIdentifier::from(origin.clone());
 
 			// Check that DID does not exist yet
@@ -325,6 +398,10 @@ pub mod pallet {
 
 			// Store new DID
 			Did::<T>::insert(did.clone(), document.clone());
+			// Identify DIDs of XCM origins by their location
+			if let Some(location) = location {
+				DidLocations::<T>::insert(did.clone(), location);
+			}
 
 			// Event
 			Self::deposit_event(Event::DidCreated { did, document });
@@ -360,7 +437,9 @@ pub mod pallet {
 				assertion,
 				services.clone(),
 				&mut services_witness,
-				|origin, document| Self::ensure_controller(ensure_signed(origin)?, document),
+				|origin, document| {
+					Self::ensure_controller(Self::ensure_did_account(origin)?, document)
+				},
 			)?;
 
 			Self::deposit_event(Event::DidUpdated { did, document });
@@ -417,7 +496,7 @@ pub mod pallet {
 			// For keeping track of Services inserts/removals
 			let mut services_witness = ServicesWitness::default();
 			Self::do_remove_did(origin, did.clone(), &mut services_witness, |origin, document| {
-				Self::ensure_controller(ensure_signed(origin)?, document)
+				Self::ensure_controller(Self::ensure_did_account(origin)?, document)
 			})?;
 			Self::deposit_event(Event::DidRemoved { did });
 
@@ -447,7 +526,7 @@ pub mod pallet {
 			did: DidIdentifierOf<T>,
 			services: BoundedVec<ServiceInfo<T>, T::MaxServices>,
 		) -> DispatchResultWithPostInfo {
-			let controller = ensure_signed(origin)?;
+			let controller = Self::ensure_did_account(origin)?;
 			// For keeping track of Services inserts/removals
 			let mut services_witness = ServicesWitness::default();
 			// Try to mutate document
@@ -479,7 +558,7 @@ pub mod pallet {
 			did: DidIdentifierOf<T>,
 			services_keys: ServiceKeysOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let controller = ensure_signed(origin)?;
+			let controller = Self::ensure_did_account(origin)?;
 
 			// For keeping track of Services inserts/removals
 			let mut services_witness = ServicesWitness::default();
@@ -513,7 +592,7 @@ pub mod pallet {
 			credentials: BoundedVec<CredentialOf<T>, T::MaxCredentialsTypes>,
 			verifiable_credential_hash: HashOf<T>,
 		) -> DispatchResult {
-			let controller = ensure_signed(origin)?;
+			let controller = Self::ensure_did_account(origin)?;
 
 			// Ensure origin is the issuer's controller
 			let document = Did::<T>::get(&issuer_did).ok_or(Error::<T>::DidNotFound)?;
@@ -557,7 +636,7 @@ pub mod pallet {
 			subject_did: DidIdentifierOf<T>,
 			credentials: BoundedVec<CredentialOf<T>, T::MaxCredentialsTypes>,
 		) -> DispatchResult {
-			let controller = ensure_signed(origin)?;
+			let controller = Self::ensure_did_account(origin)?;
 
 			// Ensure origin is the issuer's controller
 			let document = Did::<T>::get(&issuer_did).ok_or(Error::<T>::DidNotFound)?;
@@ -695,9 +774,132 @@ pub mod pallet {
 			Self::deposit_event(Event::CredentialTypesRemoved { credentials });
 			Ok(())
 		}
+
+		/// Allows `querier` to query credentials over XCM. Answers are dispatched on the chain of
+		/// `querier` as the call with the pallet and call index `response_call`, paid with
+		/// `fee_amount` of `fee_asset` from the sovereign account of this chain there, see
+		/// `query_credential`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::add_credential_querier())]
+		pub fn add_credential_querier(
+			origin: OriginFor<T>,
+			querier: Box<VersionedMultiLocation>,
+			response_call: ResponseCallIndex,
+			fee_asset: Box<VersionedMultiLocation>,
+			fee_amount: u128,
+		) -> DispatchResult {
+			// Origin ONLY GovernanceOrigin
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let querier: MultiLocation =
+				(*querier).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let fee_asset: MultiLocation =
+				(*fee_asset).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			ensure!(
+				!CredentialQueriers::<T>::contains_key(querier),
+				Error::<T>::CredentialQuerierAlreadyAdded
+			);
+
+			let info = CredentialQuerierInfo { response_call, fee_asset, fee_amount };
+			CredentialQueriers::<T>::insert(querier, info);
+			Self::deposit_event(Event::CredentialQuerierAdded { querier, info });
+			Ok(())
+		}
+
+		/// Stops `querier` from querying credentials over XCM.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_credential_querier())]
+		pub fn remove_credential_querier(
+			origin: OriginFor<T>,
+			querier: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			// Origin ONLY GovernanceOrigin
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let querier: MultiLocation =
+				(*querier).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			CredentialQueriers::<T>::take(querier)
+				.ok_or(Error::<T>::CredentialQuerierDoesNotExist)?;
+
+			Self::deposit_event(Event::CredentialQuerierRemoved { querier });
+			Ok(())
+		}
+
+		/// Answers whether `did` holds `credential` from `issuer`, and `issuer` is active.
+		///
+		/// Meant to be dispatched through XCM `Transact` by an allowed querier, which pays for it
+		/// with the execution fees of its message. The answer is sent back to the querier as a
+		/// `Transact` with `OriginKind::Xcm`, weighing at most `max_response_weight`, of the call
+		/// registered for the querier. The call is encoded as
+		///
+		/// `response_call ++ SCALE((query_id: u64, verifiable_credential_hash: Option<Vec<u8>>))`
+		///
+		/// so the querier can declare it as e.g.
+		/// `fn credential_response(origin, query_id: u64, verifiable_credential_hash:
+		/// Option<Vec<u8>>)`. `verifiable_credential_hash` is `None` if `did` does not hold
+		/// `credential` from `issuer`. The querier must check the call is dispatched by the XCM
+		/// origin of this chain.
+		///
+		/// The execution of the answer is bought with the fee registered for the querier, withdrawn
+		/// from the sovereign account of this chain on the chain of the querier. What is not used
+		/// is deposited back to it.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::query_credential())]
+		pub fn query_credential(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			did: DidIdentifierOf<T>,
+			credential: CredentialOf<T>,
+			issuer: DidIdentifierOf<T>,
+			max_response_weight: Weight,
+		) -> DispatchResult {
+			let querier = T::CredentialQueryOrigin::ensure_origin(origin)?;
+			let info =
+				CredentialQueriers::<T>::get(querier).ok_or(Error::<T>::NotCredentialQuerier)?;
+
+			let verifiable_credential_hash =
+				Self::valid_credential_hash(&did, &credential, &issuer);
+			let fee: MultiAsset = (info.fee_asset, info.fee_amount).into();
+			let this_chain = MultiLocation::here()
+				.reanchored(&querier, T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::CredentialQueryResponseFailed)?;
+			let message = Xcm(vec![
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution { fees: fee, weight_limit: WeightLimit::Unlimited },
+				Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: max_response_weight,
+					call: (info.response_call, query_id, &verifiable_credential_hash)
+						.encode()
+						.into(),
+				},
+				RefundSurplus,
+				DepositAsset {
+					assets: WildMultiAsset::AllCounted(1).into(),
+					beneficiary: this_chain,
+				},
+			]);
+			send_xcm::<T::XcmSender>(querier, message)
+				.map_err(|_| Error::<T>::CredentialQueryResponseFailed)?;
+
+			Self::deposit_event(Event::CredentialQueried {
+				querier,
+				query_id,
+				did,
+				credential,
+				issuer,
+				verifiable_credential_hash,
+			});
+			Ok(())
+		}
 	}
 }
 
+/// Helper to set up the credential query benchmark.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<RuntimeOrigin> {
+	/// An origin passing `CredentialQueryOrigin` for a location responses can be sent to.
+	fn credential_querier() -> RuntimeOrigin;
+}
+
 impl<T: Config> Pallet<T> {
 	/// Updates `document` with specified fields. Inserting services may fail.
 	fn do_update_did(
@@ -777,6 +979,7 @@ impl<T: Config> Pallet<T> {
 				Self::do_remove_issuer(did.clone())?;
 			}
 
+			DidLocations::<T>::remove(&did);
 			T::Currency::unreserve(&did.clone().into(), T::DidDeposit::get());
 			Ok(())
 		})
@@ -915,6 +1118,63 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns true if `subject` has a DID and holds `credential` issued by one of `issuers` that
+	/// is still active.
+	/// Reads storage directly for each of `issuers`, so callers outside of a weighed dispatchable
+	/// (e.g. call filters) stay bounded by the length of the list they pass.
+	pub fn has_valid_credential(
+		subject: &DidIdentifierOf<T>,
+		credential: &CredentialOf<T>,
+		issuers: &[DidIdentifierOf<T>],
+	) -> bool {
+		Did::<T>::contains_key(subject)
+			&& issuers.iter().any(|issuer| {
+				IssuedCredentials::<T>::contains_key((subject, credential, issuer))
+					&& Self::ensure_issuer_is_active(issuer).is_ok()
+			})
+	}
+
+	/// Returns the `verifiable_credential_hash` of `credential` if `subject` has a DID and holds
+	/// it from `issuer`, and `issuer` is active.
+	pub fn valid_credential_hash(
+		subject: &DidIdentifierOf<T>,
+		credential: &CredentialOf<T>,
+		issuer: &DidIdentifierOf<T>,
+	) -> Option<HashOf<T>> {
+		if !Did::<T>::contains_key(subject) || Self::ensure_issuer_is_active(issuer).is_err() {
+			return None;
+		}
+		IssuedCredentials::<T>::get((subject, credential, issuer))
+			.map(|info| info.verifiable_credential_hash)
+	}
+
+	/// Returns the DID created from an XCM origin at `location`, if any.
+	pub fn location_did(location: &MultiLocation) -> Option<DidIdentifierOf<T>> {
+		let did = T::DidIdentifier::from(T::LocationToAccountId::convert_location(location)?);
+		(DidLocations::<T>::get(&did) == Some(*location)).then_some(did)
+	}
+
+	/// Ensures that origin is signed or an XCM location, returning the account managing DIDs for
+	/// it and the location, if any.
+	fn ensure_did_origin(
+		origin: OriginFor<T>,
+	) -> Result<(T::AccountId, Option<MultiLocation>), DispatchError> {
+		match T::XcmDidOrigin::try_origin(origin) {
+			Ok(location) => {
+				let who = T::LocationToAccountId::convert_location(&location)
+					.ok_or(Error::<T>::LocationNotConvertible)?;
+				Ok((who, Some(location)))
+			},
+			Err(origin) => Ok((ensure_signed(origin)?, None)),
+		}
+	}
+
+	/// Ensures that origin is signed or an XCM location, returning the account managing DIDs for
+	/// it.
+	fn ensure_did_account(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+		Ok(Self::ensure_did_origin(origin)?.0)
+	}
+
 	/// Ensures that `who` is the controller of the did document
 	fn ensure_controller(who: T::AccountId, document: &Document<T>) -> DispatchResult {
 		ensure!(document.controller == T::DidIdentifier::from(who), Error::<T>::NotController);
@@ -20,11 +20,13 @@ use frame_support::{
 	parameter_types,
 	traits::{ConstU16, ConstU64},
 };
+use parity_scale_codec::Encode;
 use sp_core::{H160, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use xcm::latest::prelude::*;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -86,6 +88,7 @@ parameter_types! {
 	pub const MaxServices: u8 = 10;
 	pub const MaxHash: u32 = 512;
 	pub const DidDeposit: u64 = 5;
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(2000));
 }
 
 impl pallet_did::Config for Test {
@@ -102,7 +105,127 @@ impl pallet_did::Config for Test {
 	type MaxCredentialsTypes = MaxCredentialsTypes;
 	type MaxCredentialTypeLength = MaxCredentialTypeLength;
 	type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+	type CredentialQueryOrigin = EnsureSiblingQuerier;
+	type XcmSender = TestSendXcm;
+	type UniversalLocation = UniversalLocation;
+	type XcmDidOrigin = EnsureSiblingDidOrigin;
+	type LocationToAccountId = SiblingToAccountId;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = EnsureSiblingQuerier;
+}
+
+/// Parachain the test XCM sender can not route to.
+pub(crate) const UNROUTABLE_PARA: u32 = 999;
+
+/// Location of the sibling parachain with the id of a signed account.
+pub(crate) fn sibling(id: u64) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(id as u32)))
+}
+
+/// Treats signed origins as coming from the sibling parachain with the id of the account.
+pub struct EnsureSiblingQuerier;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingQuerier {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) => Ok(sibling(who)),
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(ALICE))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<RuntimeOrigin> for EnsureSiblingQuerier {
+	fn credential_querier() -> RuntimeOrigin {
+		RuntimeOrigin::signed(ALICE)
+	}
+}
+
+/// Offset of the accounts whose signed origins are treated as XCM origins of sibling parachains.
+pub(crate) const XCM_ORIGIN_OFFSET: u64 = 1_000;
+/// Offset of the sovereign accounts of sibling parachains.
+pub(crate) const SOVEREIGN_ACCOUNT_OFFSET: u64 = 10_000;
+pub(crate) const SIBLING_PARA: u32 = 7;
+
+/// XCM origin of the sibling parachain `para`.
+pub(crate) fn xcm_origin(para: u32) -> RuntimeOrigin {
+	RuntimeOrigin::signed(XCM_ORIGIN_OFFSET + para as u64)
+}
+
+/// Sovereign account of the sibling parachain `para`.
+pub(crate) fn sovereign_account(para: u32) -> u64 {
+	SOVEREIGN_ACCOUNT_OFFSET + para as u64
+}
+
+/// Treats signed origins of accounts from `XCM_ORIGIN_OFFSET` on as XCM origins of the sibling
+/// parachain with the id of the account minus the offset.
+pub struct EnsureSiblingDidOrigin;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingDidOrigin {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who)
+				if (XCM_ORIGIN_OFFSET..SOVEREIGN_ACCOUNT_OFFSET).contains(&who) =>
+			{
+				Ok(sibling(who - XCM_ORIGIN_OFFSET))
+			},
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(xcm_origin(SIBLING_PARA))
+	}
+}
+
+/// Converts sibling parachain locations to their sovereign account.
+pub struct SiblingToAccountId;
+impl ConvertLocation<u64> for SiblingToAccountId {
+	fn convert_location(location: &MultiLocation) -> Option<u64> {
+		match location {
+			MultiLocation { parents: 1, interior: X1(Parachain(id)) } => {
+				Some(sovereign_account(*id))
+			},
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+}
+
+/// Records sent messages in `SentXcm`.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let msg = msg.take().ok_or(SendError::MissingArgument)?;
+		if dest == sibling(UNROUTABLE_PARA.into()) {
+			return Err(SendError::Unroutable);
+		}
+		Ok(((dest, msg), MultiAssets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+		SentXcm::mutate(|sent| sent.push(ticket));
+		Ok(hash)
+	}
 }
 
 pub(crate) const ALICE: u64 = 1;
@@ -117,7 +240,15 @@ pub(crate) const ACCOUNT_04: u64 = 4;
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
 		balances: pallet_balances::GenesisConfig::<Test> {
-			balances: vec![(0, 2), (1, 10), (2, 20), (3, 30), (4, 40), (5, 50)],
+			balances: vec![
+				(0, 2),
+				(1, 10),
+				(2, 20),
+				(3, 30),
+				(4, 40),
+				(5, 50),
+				(sovereign_account(SIBLING_PARA), 100),
+			],
 		},
 	}
 	.build_storage()
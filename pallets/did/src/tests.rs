@@ -16,7 +16,8 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok, bounded_vec, error::BadOrigin};
+use frame_support::{assert_noop, assert_ok, bounded_vec, error::BadOrigin, weights::Weight};
+use parity_scale_codec::Decode;
 use sp_core::H160;
 use sp_runtime::traits::Hash;
 
@@ -594,6 +595,128 @@ fn remove_did_services_fails_if_service_not_in_did() {
 	});
 }
 
+#[test]
+fn create_did_from_xcm_origin_works() {
+	new_test_ext().execute_with(|| {
+		let did = sovereign_account(SIBLING_PARA);
+		let services = default_services();
+		let mut services_keys = hash_services(&services);
+		services_keys.sort();
+		let expected_document = Document {
+			controller: did,
+			authentication: AuthenticationMethod { controller: H160::from([0u8; 20]) },
+			assertion_method: None,
+			services: services_keys,
+		};
+
+		assert_ok!(DID::create_did(
+			xcm_origin(SIBLING_PARA),
+			did,
+			H160::from([0u8; 20]),
+			None,
+			services
+		));
+
+		assert_eq!(Balances::reserved_balance(&did), DidDeposit::get());
+		assert_eq!(DID::dids(did), Some(expected_document.clone()));
+		assert_eq!(DID::did_locations(did), Some(sibling(SIBLING_PARA.into())));
+		assert_eq!(DID::location_did(&sibling(SIBLING_PARA.into())), Some(did));
+		assert!(events().contains(&Event::<Test>::DidCreated { did, document: expected_document }));
+	});
+}
+
+#[test]
+fn signed_dids_have_no_location() {
+	new_test_ext().execute_with(|| {
+		// The sovereign account of a sibling can also sign for itself locally
+		let did = sovereign_account(SIBLING_PARA);
+		create_default_did(did, did);
+
+		assert_eq!(DID::did_locations(did), None);
+		assert_eq!(DID::location_did(&sibling(SIBLING_PARA.into())), None);
+	});
+}
+
+#[test]
+fn xcm_origin_manages_its_did() {
+	new_test_ext().execute_with(|| {
+		let origin = xcm_origin(SIBLING_PARA);
+		let did = sovereign_account(SIBLING_PARA);
+		assert_ok!(DID::create_did(
+			origin.clone(),
+			did,
+			H160::from([0u8; 20]),
+			None,
+			BoundedVec::default()
+		));
+
+		assert_ok!(DID::update_did(
+			origin.clone(),
+			did,
+			None,
+			Some(H160::from([1u8; 20])),
+			None,
+			None
+		));
+		assert_eq!(DID::dids(did).unwrap().authentication.controller, H160::from([1u8; 20]));
+
+		assert_ok!(DID::add_did_services(origin.clone(), did, default_services()));
+		assert_services(default_services(), 1);
+		assert_ok!(DID::remove_did_services(
+			origin.clone(),
+			did,
+			hash_services(&default_services())
+		));
+		assert_services_do_not_exist(default_services());
+
+		// Other origins do not control the DID
+		assert_noop!(
+			DID::remove_did(RuntimeOrigin::signed(ALICE), did),
+			Error::<Test>::NotController
+		);
+
+		assert_ok!(DID::remove_did(origin, did));
+		assert_eq!(DID::dids(did), None);
+		assert_eq!(DID::did_locations(did), None);
+		assert_eq!(DID::location_did(&sibling(SIBLING_PARA.into())), None);
+		assert_eq!(Balances::reserved_balance(&did), 0);
+	});
+}
+
+#[test]
+fn xcm_origin_issues_credentials() {
+	new_test_ext().execute_with(|| {
+		let origin = xcm_origin(SIBLING_PARA);
+		let issuer = sovereign_account(SIBLING_PARA);
+		let creds: BoundedVec<BoundedVec<u8, MaxCredentialTypeLength>, MaxCredentialsTypes> =
+			bounded_vec![bounded_vec![0, 0]];
+		let verifiable_credential_hash: HashOf<Test> = bounded_vec![1, 2, 3, 4, 5];
+
+		assert_ok!(DID::create_did(
+			origin.clone(),
+			issuer,
+			H160::from([0u8; 20]),
+			None,
+			BoundedVec::default()
+		));
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+		assert_ok!(DID::add_credentials_type(RuntimeOrigin::root(), creds.clone()));
+		assert_ok!(DID::add_issuer(RuntimeOrigin::root(), issuer));
+
+		assert_ok!(DID::issue_credentials(
+			origin.clone(),
+			issuer,
+			ACCOUNT_02,
+			creds.clone(),
+			verifiable_credential_hash
+		));
+		assert!(DID::has_valid_credential(&ACCOUNT_02, &creds[0], &[issuer]));
+
+		assert_ok!(DID::revoke_credentials(origin, issuer, ACCOUNT_02, creds.clone()));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &creds[0], &[issuer]));
+	});
+}
+
 // ** Issuer Tests **
 
 #[test]
@@ -1048,3 +1171,382 @@ fn force_revoke_credentials_works() {
 		}));
 	});
 }
+
+#[test]
+fn has_valid_credential_works() {
+	new_test_ext().execute_with(|| {
+		let issuer_origin = RuntimeOrigin::signed(ACCOUNT_01);
+		let root = RuntimeOrigin::root();
+
+		create_default_did(ACCOUNT_01, ACCOUNT_01);
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+
+		let creds: BoundedVec<BoundedVec<u8, MaxCredentialTypeLength>, MaxCredentialsTypes> =
+			bounded_vec![bounded_vec![0, 0]];
+		let credential: CredentialOf<Test> = bounded_vec![0, 0];
+		let verifiable_credential_hash: HashOf<Test> = bounded_vec![1, 2, 3, 4, 5];
+
+		assert_ok!(DID::add_credentials_type(root.clone(), creds.clone()));
+		assert_ok!(DID::add_issuer(root.clone(), ACCOUNT_01));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_01]));
+
+		assert_ok!(DID::issue_credentials(
+			issuer_origin.clone(),
+			ACCOUNT_01,
+			ACCOUNT_02,
+			creds.clone(),
+			verifiable_credential_hash
+		));
+		assert!(DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_01]));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &bounded_vec![0, 1], &[ACCOUNT_01]));
+		// Only the listed issuers are checked
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &credential, &[]));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_03]));
+		assert!(DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_03, ACCOUNT_01]));
+
+		// Credentials of a revoked issuer are no longer valid
+		assert_ok!(DID::revoke_issuer(root.clone(), ACCOUNT_01));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_01]));
+		assert_ok!(DID::reactivate_issuer(root.clone(), ACCOUNT_01));
+		assert!(DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_01]));
+
+		// Subject without a DID has no valid credentials
+		assert_ok!(DID::remove_did(RuntimeOrigin::signed(ACCOUNT_02), ACCOUNT_02));
+		assert!(!DID::has_valid_credential(&ACCOUNT_02, &credential, &[ACCOUNT_01]));
+	});
+}
+
+#[test]
+fn add_credential_querier_works() {
+	new_test_ext().execute_with(|| {
+		let querier = sibling(ALICE);
+
+		assert_noop!(
+			DID::add_credential_querier(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(querier.into()),
+				RESPONSE_CALL,
+				Box::new(fee_asset().into()),
+				FEE_AMOUNT
+			),
+			BadOrigin
+		);
+
+		assert_ok!(DID::add_credential_querier(
+			RuntimeOrigin::root(),
+			Box::new(querier.into()),
+			RESPONSE_CALL,
+			Box::new(fee_asset().into()),
+			FEE_AMOUNT
+		));
+		assert!(CredentialQueriers::<Test>::contains_key(querier));
+		assert_eq!(CredentialQueriers::<Test>::get(querier), Some(querier_info()));
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::CredentialQuerierAdded { querier, info: querier_info() }]
+		);
+
+		assert_noop!(
+			DID::add_credential_querier(
+				RuntimeOrigin::root(),
+				Box::new(querier.into()),
+				RESPONSE_CALL,
+				Box::new(fee_asset().into()),
+				FEE_AMOUNT
+			),
+			Error::<Test>::CredentialQuerierAlreadyAdded
+		);
+	});
+}
+
+#[test]
+fn remove_credential_querier_works() {
+	new_test_ext().execute_with(|| {
+		let querier = sibling(ALICE);
+
+		assert_noop!(
+			DID::remove_credential_querier(RuntimeOrigin::root(), Box::new(querier.into())),
+			Error::<Test>::CredentialQuerierDoesNotExist
+		);
+		assert_ok!(DID::add_credential_querier(
+			RuntimeOrigin::root(),
+			Box::new(querier.into()),
+			RESPONSE_CALL,
+			Box::new(fee_asset().into()),
+			FEE_AMOUNT
+		));
+		assert_noop!(
+			DID::remove_credential_querier(RuntimeOrigin::signed(ALICE), Box::new(querier.into())),
+			BadOrigin
+		);
+
+		assert_ok!(DID::remove_credential_querier(RuntimeOrigin::root(), Box::new(querier.into())));
+		assert!(!CredentialQueriers::<Test>::contains_key(querier));
+		assert!(events().contains(&Event::<Test>::CredentialQuerierRemoved { querier }));
+	});
+}
+
+/// Index of `credential_response` on the sibling chain of the querier.
+const RESPONSE_CALL: ResponseCallIndex = [50, 3];
+
+/// Fee paying for the execution of each answer on the sibling chain of the querier.
+const FEE_AMOUNT: u128 = 1_000;
+
+fn fee_asset() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+fn querier_info() -> CredentialQuerierInfo {
+	CredentialQuerierInfo {
+		response_call: RESPONSE_CALL,
+		fee_asset: fee_asset(),
+		fee_amount: FEE_AMOUNT,
+	}
+}
+
+/// Calls of the sibling chain of the querier, as its runtime declares them.
+#[derive(Decode, Debug, PartialEq)]
+enum SiblingCall {
+	#[codec(index = 50)]
+	Credentials(SiblingCredentialsCall),
+}
+
+#[derive(Decode, Debug, PartialEq)]
+enum SiblingCredentialsCall {
+	#[codec(index = 3)]
+	CredentialResponse { query_id: QueryId, verifiable_credential_hash: Option<Vec<u8>> },
+}
+
+/// Decodes the answer to a credential query the way the sibling chain of the querier does.
+fn credential_response(message: &Xcm<()>) -> (Weight, SiblingCall) {
+	let fee: MultiAsset = (fee_asset(), FEE_AMOUNT).into();
+	let (withdraw, buy, transact, refund, deposit) = match &message.0[..] {
+		[withdraw, buy, transact, refund, deposit] => (withdraw, buy, transact, refund, deposit),
+		_ => panic!("unexpected response {:?}", message),
+	};
+
+	// The execution is paid from the sovereign account of this chain, which gets the rest back
+	assert_eq!(withdraw, &WithdrawAsset(fee.clone().into()));
+	assert_eq!(buy, &BuyExecution { fees: fee, weight_limit: WeightLimit::Unlimited });
+	assert_eq!(refund, &RefundSurplus);
+	assert_eq!(
+		deposit,
+		&DepositAsset { assets: WildMultiAsset::AllCounted(1).into(), beneficiary: sibling(2000) }
+	);
+	match transact {
+		Transact { origin_kind: OriginKind::Xcm, require_weight_at_most, call } => {
+			let call = SiblingCall::decode(&mut &call.clone().into_encoded()[..]).unwrap();
+			(*require_weight_at_most, call)
+		},
+		_ => panic!("unexpected response {:?}", message),
+	}
+}
+
+fn sibling_call(query_id: QueryId, verifiable_credential_hash: Option<Vec<u8>>) -> SiblingCall {
+	SiblingCall::Credentials(SiblingCredentialsCall::CredentialResponse {
+		query_id,
+		verifiable_credential_hash,
+	})
+}
+
+#[test]
+fn query_credential_works() {
+	new_test_ext().execute_with(|| {
+		let root = RuntimeOrigin::root();
+		let querier = sibling(ACCOUNT_03);
+		let max_weight = Weight::from_parts(1_000, 1_000);
+
+		create_default_did(ACCOUNT_01, ACCOUNT_01);
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+
+		let creds: BoundedVec<BoundedVec<u8, MaxCredentialTypeLength>, MaxCredentialsTypes> =
+			bounded_vec![bounded_vec![0, 0]];
+		let credential: CredentialOf<Test> = bounded_vec![0, 0];
+		let verifiable_credential_hash: HashOf<Test> = bounded_vec![1, 2, 3, 4, 5];
+
+		assert_ok!(DID::add_credentials_type(root.clone(), creds.clone()));
+		assert_ok!(DID::add_issuer(root.clone(), ACCOUNT_01));
+		assert_ok!(DID::add_credential_querier(
+			root.clone(),
+			Box::new(querier.into()),
+			RESPONSE_CALL,
+			Box::new(fee_asset().into()),
+			FEE_AMOUNT
+		));
+
+		// Subject does not hold the credential yet
+		assert_ok!(DID::query_credential(
+			RuntimeOrigin::signed(ACCOUNT_03),
+			1,
+			ACCOUNT_02,
+			credential.clone(),
+			ACCOUNT_01,
+			max_weight
+		));
+		let sent = SentXcm::take();
+		assert_eq!(sent.len(), 1);
+		assert_eq!(sent[0].0, querier);
+		assert_eq!(credential_response(&sent[0].1), (max_weight, sibling_call(1, None)));
+
+		assert_ok!(DID::issue_credentials(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			ACCOUNT_01,
+			ACCOUNT_02,
+			creds,
+			verifiable_credential_hash.clone()
+		));
+		System::reset_events();
+
+		assert_ok!(DID::query_credential(
+			RuntimeOrigin::signed(ACCOUNT_03),
+			2,
+			ACCOUNT_02,
+			credential.clone(),
+			ACCOUNT_01,
+			max_weight
+		));
+		let sent = SentXcm::take();
+		assert_eq!(
+			credential_response(&sent[0].1),
+			(max_weight, sibling_call(2, Some(verifiable_credential_hash.to_vec())))
+		);
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::CredentialQueried {
+				querier,
+				query_id: 2,
+				did: ACCOUNT_02,
+				credential: credential.clone(),
+				issuer: ACCOUNT_01,
+				verifiable_credential_hash: Some(verifiable_credential_hash),
+			}]
+		);
+
+		// Only the credentials of the given issuer are reported
+		assert_ok!(DID::query_credential(
+			RuntimeOrigin::signed(ACCOUNT_03),
+			3,
+			ACCOUNT_02,
+			credential.clone(),
+			ACCOUNT_03,
+			max_weight
+		));
+		let sent = SentXcm::take();
+		assert_eq!(credential_response(&sent[0].1), (max_weight, sibling_call(3, None)));
+
+		// Credentials of a revoked issuer are not reported
+		assert_ok!(DID::revoke_issuer(root, ACCOUNT_01));
+		assert_ok!(DID::query_credential(
+			RuntimeOrigin::signed(ACCOUNT_03),
+			4,
+			ACCOUNT_02,
+			credential,
+			ACCOUNT_01,
+			max_weight
+		));
+		let sent = SentXcm::take();
+		assert_eq!(credential_response(&sent[0].1), (max_weight, sibling_call(4, None)));
+	});
+}
+
+#[test]
+fn query_credential_sends_long_hashes_in_full() {
+	new_test_ext().execute_with(|| {
+		let root = RuntimeOrigin::root();
+		let creds: BoundedVec<BoundedVec<u8, MaxCredentialTypeLength>, MaxCredentialsTypes> =
+			bounded_vec![bounded_vec![0, 0]];
+		let verifiable_credential_hash: HashOf<Test> = vec![7; 512].try_into().unwrap();
+
+		create_default_did(ACCOUNT_01, ACCOUNT_01);
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+		assert_ok!(DID::add_credentials_type(root.clone(), creds.clone()));
+		assert_ok!(DID::add_issuer(root.clone(), ACCOUNT_01));
+		assert_ok!(DID::issue_credentials(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			ACCOUNT_01,
+			ACCOUNT_02,
+			creds,
+			verifiable_credential_hash.clone()
+		));
+		assert_ok!(DID::add_credential_querier(
+			root,
+			Box::new(sibling(ACCOUNT_03).into()),
+			RESPONSE_CALL,
+			Box::new(fee_asset().into()),
+			FEE_AMOUNT
+		));
+
+		assert_ok!(DID::query_credential(
+			RuntimeOrigin::signed(ACCOUNT_03),
+			1,
+			ACCOUNT_02,
+			bounded_vec![0, 0],
+			ACCOUNT_01,
+			Weight::zero()
+		));
+
+		let sent = SentXcm::take();
+		assert_eq!(
+			credential_response(&sent[0].1).1,
+			sibling_call(1, Some(verifiable_credential_hash.to_vec()))
+		);
+	});
+}
+
+#[test]
+fn query_credential_fails_for_unknown_queriers() {
+	new_test_ext().execute_with(|| {
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+
+		assert_noop!(
+			DID::query_credential(
+				RuntimeOrigin::root(),
+				1,
+				ACCOUNT_02,
+				bounded_vec![0, 0],
+				ACCOUNT_01,
+				Weight::zero()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			DID::query_credential(
+				RuntimeOrigin::signed(ACCOUNT_03),
+				1,
+				ACCOUNT_02,
+				bounded_vec![0, 0],
+				ACCOUNT_01,
+				Weight::zero()
+			),
+			Error::<Test>::NotCredentialQuerier
+		);
+		assert!(SentXcm::take().is_empty());
+	});
+}
+
+#[test]
+fn query_credential_fails_if_response_can_not_be_sent() {
+	new_test_ext().execute_with(|| {
+		let querier = sibling(UNROUTABLE_PARA.into());
+		create_default_did(ACCOUNT_02, ACCOUNT_02);
+		assert_ok!(DID::add_credential_querier(
+			RuntimeOrigin::root(),
+			Box::new(querier.into()),
+			RESPONSE_CALL,
+			Box::new(fee_asset().into()),
+			FEE_AMOUNT
+		));
+
+		assert_noop!(
+			DID::query_credential(
+				RuntimeOrigin::signed(UNROUTABLE_PARA.into()),
+				1,
+				ACCOUNT_02,
+				bounded_vec![0, 0],
+				ACCOUNT_01,
+				Weight::zero()
+			),
+			Error::<Test>::CredentialQueryResponseFailed
+		);
+	});
+}
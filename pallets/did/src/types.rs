@@ -133,3 +133,14 @@ pub struct ServicesWitness {
 pub struct CredentialInfo<T: Config> {
 	pub verifiable_credential_hash: HashOf<T>,
 }
+
+/// How the answers to the credential queries of a querier are delivered to its chain.
+#[derive(Clone, Copy, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CredentialQuerierInfo {
+	/// Pallet and call index, on the chain of the querier, of the call answering its queries.
+	pub response_call: ResponseCallIndex,
+	/// Asset, as seen from the chain of the querier, paying for the execution of the answers.
+	pub fee_asset: MultiLocation,
+	/// Amount of `fee_asset` withdrawn for each answer. What is not used is refunded.
+	pub fee_amount: u128,
+}
@@ -36,6 +36,10 @@
 // --output=./pallets/did/src/weights.rs
 // --header=./file_header.txt
 // --template=./scripts/frame-weight-template.hbs
+//
+// PLACEHOLDER VALUES, NOT MEASURED: `add_credential_querier`, `remove_credential_querier` and
+// `query_credential`, and the `DidLocations` write of `create_did` and `remove_did`, were added by
+// hand after the run above. They must be replaced by running the `pallet_did` benchmarks again.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -58,6 +62,9 @@ pub trait WeightInfo {
 	fn reactivate_issuer() -> Weight;
 	fn add_credentials_type(m: u32, ) -> Weight;
 	fn remove_credentials_type(m: u32, ) -> Weight;
+	fn add_credential_querier() -> Weight;
+	fn remove_credential_querier() -> Weight;
+	fn query_credential() -> Weight;
 }
 
 /// Weights for pallet_did using the Substrate node and recommended hardware.
@@ -67,6 +74,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DID Did (max_values: None, max_size: Some(442), added: 2917, mode: MaxEncodedLen)
 	/// Storage: DID Services (r:10 w:10)
 	/// Proof: DID Services (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: DID DidLocations (r:0 w:1)
+	/// Proof: DID DidLocations (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 10]`.
 	fn create_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -79,7 +88,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(3_734_207, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -101,6 +110,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DID Services (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
 	/// Storage: DID Issuers (r:1 w:0)
 	/// Proof: DID Issuers (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: DID DidLocations (r:0 w:1)
+	/// Proof: DID DidLocations (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 10]`.
 	fn remove_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -113,7 +124,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(3_312_251, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -265,6 +276,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: DID CredentialQueriers (r:1 w:1)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	fn add_credential_querier() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4019))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: DID CredentialQueriers (r:1 w:1)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	fn remove_credential_querier() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4019))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: DID CredentialQueriers (r:1 w:0)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	/// Storage: DID Did (r:1 w:0)
+	/// Proof: DID Did (max_values: None, max_size: Some(442), added: 2917, mode: MaxEncodedLen)
+	/// Storage: DID IssuedCredentials (r:1 w:0)
+	/// Proof: DID IssuedCredentials (max_values: None, max_size: Some(679), added: 3154, mode: MaxEncodedLen)
+	/// Storage: DID Issuers (r:1 w:0)
+	/// Proof: DID Issuers (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn query_credential() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(43_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7298))
+			.saturating_add(T::DbWeight::get().reads(4))
+	}
 }
 
 // For backwards compatibility and tests
@@ -273,6 +316,8 @@ impl WeightInfo for () {
 	/// Proof: DID Did (max_values: None, max_size: Some(442), added: 2917, mode: MaxEncodedLen)
 	/// Storage: DID Services (r:10 w:10)
 	/// Proof: DID Services (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: DID DidLocations (r:0 w:1)
+	/// Proof: DID DidLocations (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 10]`.
 	fn create_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -285,7 +330,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(3_734_207, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -307,6 +352,8 @@ impl WeightInfo for () {
 	/// Proof: DID Services (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
 	/// Storage: DID Issuers (r:1 w:0)
 	/// Proof: DID Issuers (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: DID DidLocations (r:0 w:1)
+	/// Proof: DID DidLocations (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 10]`.
 	fn remove_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -319,7 +366,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(3_312_251, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -471,4 +518,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: DID CredentialQueriers (r:1 w:1)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	fn add_credential_querier() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4019))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: DID CredentialQueriers (r:1 w:1)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	fn remove_credential_querier() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4019))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: DID CredentialQueriers (r:1 w:0)
+	/// Proof: DID CredentialQueriers (max_values: None, max_size: Some(554), added: 3029, mode: MaxEncodedLen)
+	/// Storage: DID Did (r:1 w:0)
+	/// Proof: DID Did (max_values: None, max_size: Some(442), added: 2917, mode: MaxEncodedLen)
+	/// Storage: DID IssuedCredentials (r:1 w:0)
+	/// Proof: DID IssuedCredentials (max_values: None, max_size: Some(679), added: 3154, mode: MaxEncodedLen)
+	/// Storage: DID Issuers (r:1 w:0)
+	/// Proof: DID Issuers (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn query_credential() -> Weight {
+		// Placeholder, not benchmarked.
+		Weight::from_parts(43_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7298))
+			.saturating_add(RocksDbWeight::get().reads(4))
+	}
 }
@@ -0,0 +1,104 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks of the HRMP manager, including sending the messages to the relay chain with the
+//! runtime `XcmSender`.
+
+use crate::*;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{Get, Hooks},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+use super::Pallet as HrmpManager;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn other_para_id<T: Config>(index: u32) -> ParaId {
+	ParaId::from(u32::from(T::SelfParaId::get()) + 1 + index)
+}
+
+/// Fills the pending channels up to `count`, with channels to other parachains.
+fn add_pending_channels<T: Config>(count: u32) {
+	for index in 0..count {
+		let recipient = other_para_id::<T>(index + 1);
+		let channel = HrmpChannelId { sender: T::SelfParaId::get(), recipient };
+		assert_ok!(HrmpManager::<T>::add_pending_channel(channel));
+	}
+}
+
+benchmarks! {
+	request_open_channel {
+		let origin = T::ChannelManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_pending_channels::<T>(T::MaxPendingChannels::get() - 1);
+		let recipient = other_para_id::<T>(0);
+	}: _<T::RuntimeOrigin>(origin, recipient, 8, 8192)
+	verify {
+		let channel = HrmpChannelId { sender: T::SelfParaId::get(), recipient };
+		assert_eq!(Channels::<T>::get(channel), Some(ChannelStatus::Pending));
+		assert_last_event::<T>(
+			Event::OpenChannelRequested { recipient, max_capacity: 8, max_message_size: 8192 }.into()
+		);
+	}
+
+	accept_open_channel {
+		let origin = T::ChannelManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_pending_channels::<T>(T::MaxPendingChannels::get() - 1);
+		let sender = other_para_id::<T>(0);
+	}: _<T::RuntimeOrigin>(origin, sender)
+	verify {
+		let channel = HrmpChannelId { sender, recipient: T::SelfParaId::get() };
+		assert_eq!(Channels::<T>::get(channel), Some(ChannelStatus::Pending));
+		assert_last_event::<T>(Event::OpenChannelAccepted { sender }.into());
+	}
+
+	close_channel {
+		let origin = T::ChannelManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_pending_channels::<T>(T::MaxPendingChannels::get() - 1);
+		// An untracked channel, which is added to the pending channels
+		let channel = HrmpChannelId { sender: T::SelfParaId::get(), recipient: other_para_id::<T>(0) };
+	}: _<T::RuntimeOrigin>(origin, channel)
+	verify {
+		assert_eq!(Channels::<T>::get(channel), Some(ChannelStatus::Closing));
+		assert_last_event::<T>(Event::CloseChannelRequested { channel }.into());
+	}
+
+	cancel_open_request {
+		let origin = T::ChannelManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_pending_channels::<T>(T::MaxPendingChannels::get());
+		// The last pending channel is searched for the longest
+		let channel = *PendingChannels::<T>::get().last().expect("channels were added");
+	}: _<T::RuntimeOrigin>(origin, channel, 1)
+	verify {
+		assert_eq!(Channels::<T>::get(channel), None);
+		assert_last_event::<T>(Event::OpenRequestCancelled { channel }.into());
+	}
+
+	on_initialize {
+		let n in 0 .. T::MaxPendingChannels::get();
+		add_pending_channels::<T>(n);
+		// Closing channels not open on the relay chain are removed
+		for channel in PendingChannels::<T>::get() {
+			Channels::<T>::insert(channel, ChannelStatus::Closing);
+		}
+	}: {
+		HrmpManager::<T>::on_initialize(BlockNumberFor::<T>::from(1u32));
+	}
+}
@@ -0,0 +1,319 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # HRMP Manager Pallet
+//!
+//! Lets governance open and close HRMP channels between this parachain and other parachains.
+//!
+//! The pallet sends the `hrmp` calls to the relay chain itself, in a `Transact` paid for from the
+//! sovereign account of this parachain on the relay chain, which must hold enough of the relay
+//! chain asset. Channels requested or accepted by this chain are pending until the relay chain
+//! opens them, and channels this chain closes are closing until the relay chain closed them,
+//! which is checked at the start of each block. Pending requests can be cancelled, after which
+//! the channels are no longer tracked.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use parity_scale_codec::Encode;
+use sp_std::{prelude::*, vec};
+use xcm::latest::{
+	send_xcm,
+	Instruction::{BuyExecution, DepositAsset, RefundSurplus, Transact, WithdrawAsset},
+	Junction::Parachain,
+	Junctions::Here,
+	MultiAsset,
+	MultiAssetFilter::Wild,
+	MultiLocation, OriginKind, SendXcm,
+	WeightLimit::Unlimited,
+	WildMultiAsset::AllCounted,
+	Xcm,
+};
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to open and close channels.
+		type ChannelManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Sends the messages to the relay chain.
+		type XcmSender: SendXcm;
+
+		/// The id of this parachain.
+		type SelfParaId: Get<ParaId>;
+
+		/// The channels open on the relay chain.
+		type RelayChannels: RelayChannels;
+
+		/// The index of the `hrmp` pallet in the relay chain runtime.
+		#[pallet::constant]
+		type HrmpPalletIndex: Get<u8>;
+
+		/// The amount of the relay chain asset withdrawn from the sovereign account of this chain
+		/// to pay for each message. What is not spent is deposited back.
+		#[pallet::constant]
+		type RelayFee: Get<u128>;
+
+		/// The weight of the `hrmp` calls on the relay chain.
+		#[pallet::constant]
+		type RelayCallWeight: Get<Weight>;
+
+		/// The maximum number of pending channels, waiting to be opened or closed.
+		#[pallet::constant]
+		type MaxPendingChannels: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Channels of this chain opened through this pallet, and their status.
+	#[pallet::storage]
+	#[pallet::getter(fn channel)]
+	pub type Channels<T: Config> = StorageMap<_, Twox64Concat, HrmpChannelId, ChannelStatus>;
+
+	/// Channels waiting for the relay chain to open or close them.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_channels)]
+	pub type PendingChannels<T: Config> =
+		StorageValue<_, BoundedVec<HrmpChannelId, T::MaxPendingChannels>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Opening a channel to `recipient` was requested.
+		OpenChannelRequested { recipient: ParaId, max_capacity: u32, max_message_size: u32 },
+		/// Opening a channel from `sender` was accepted.
+		OpenChannelAccepted { sender: ParaId },
+		/// The relay chain opened `channel`.
+		ChannelOpened { channel: HrmpChannelId },
+		/// Closing `channel` was requested.
+		CloseChannelRequested { channel: HrmpChannelId },
+		/// The relay chain closed `channel`.
+		ChannelClosed { channel: HrmpChannelId },
+		/// Cancelling the request to open `channel` was requested.
+		OpenRequestCancelled { channel: HrmpChannelId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A channel can not be opened with this parachain itself
+		InvalidParachain,
+		/// The channel is already pending or open
+		ChannelExists,
+		/// There are already `MaxPendingChannels` pending channels
+		TooManyPendingChannels,
+		/// The channel is neither from nor to this parachain
+		NotOwnChannel,
+		/// Closing the channel was already requested
+		ChannelClosing,
+		/// The channel is not waiting to be opened
+		ChannelNotPending,
+		/// The message could not be sent to the relay chain
+		SendFailure,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let pending = PendingChannels::<T>::get();
+			let pending_count = pending.len() as u32;
+			let mut still_pending = Vec::with_capacity(pending.len());
+
+			for channel in pending.iter().copied() {
+				let is_open = T::RelayChannels::is_open(&channel);
+				match Channels::<T>::get(channel) {
+					Some(ChannelStatus::Pending) if is_open => {
+						Channels::<T>::insert(channel, ChannelStatus::Open);
+						Self::deposit_event(Event::ChannelOpened { channel });
+					},
+					Some(ChannelStatus::Closing) if !is_open => {
+						Channels::<T>::remove(channel);
+						Self::deposit_event(Event::ChannelClosed { channel });
+					},
+					_ => still_pending.push(channel),
+				}
+			}
+			if still_pending.len() != pending.len() {
+				PendingChannels::<T>::put(BoundedVec::truncate_from(still_pending));
+			}
+			T::WeightInfo::on_initialize(pending_count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request opening a channel from this parachain to `recipient`, which must accept it.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::request_open_channel())]
+		pub fn request_open_channel(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			max_capacity: u32,
+			max_message_size: u32,
+		) -> DispatchResult {
+			T::ChannelManagerOrigin::ensure_origin(origin)?;
+			Self::add_pending_channel(HrmpChannelId { sender: T::SelfParaId::get(), recipient })?;
+
+			Self::send_to_relay(HrmpCall::InitOpenChannel {
+				recipient,
+				proposed_max_capacity: max_capacity,
+				proposed_max_message_size: max_message_size,
+			})?;
+			Self::deposit_event(Event::OpenChannelRequested {
+				recipient,
+				max_capacity,
+				max_message_size,
+			});
+			Ok(())
+		}
+
+		/// Accept the channel `sender` requested to open to this parachain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::accept_open_channel())]
+		pub fn accept_open_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+			T::ChannelManagerOrigin::ensure_origin(origin)?;
+			Self::add_pending_channel(HrmpChannelId { sender, recipient: T::SelfParaId::get() })?;
+
+			Self::send_to_relay(HrmpCall::AcceptOpenChannel { sender })?;
+			Self::deposit_event(Event::OpenChannelAccepted { sender });
+			Ok(())
+		}
+
+		/// Close `channel`, from or to this parachain.
+		///
+		/// Channels opened otherwise than through this pallet can be closed as well. The channel is
+		/// closing until it is gone from the relay chain state, as the relay chain only closes it
+		/// at the next session. Only open channels are closed by the relay chain, so a pending
+		/// channel is no longer tracked once it is seen not open.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::close_channel())]
+		pub fn close_channel(origin: OriginFor<T>, channel: HrmpChannelId) -> DispatchResult {
+			T::ChannelManagerOrigin::ensure_origin(origin)?;
+			let self_para_id = T::SelfParaId::get();
+			ensure!(
+				channel.sender == self_para_id || channel.recipient == self_para_id,
+				Error::<T>::NotOwnChannel
+			);
+
+			match Channels::<T>::get(channel) {
+				Some(ChannelStatus::Closing) => return Err(Error::<T>::ChannelClosing.into()),
+				Some(ChannelStatus::Pending) => (),
+				_ => PendingChannels::<T>::try_append(channel)
+					.map_err(|_| Error::<T>::TooManyPendingChannels)?,
+			}
+			Channels::<T>::insert(channel, ChannelStatus::Closing);
+
+			Self::send_to_relay(HrmpCall::CloseChannel { channel_id: channel })?;
+			Self::deposit_event(Event::CloseChannelRequested { channel });
+			Ok(())
+		}
+
+		/// Cancel the request to open `channel`, requested or accepted by this chain, which is no
+		/// longer tracked.
+		///
+		/// `open_requests` is the number of open requests on the relay chain, which must not be
+		/// more than that.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::cancel_open_request())]
+		pub fn cancel_open_request(
+			origin: OriginFor<T>,
+			channel: HrmpChannelId,
+			open_requests: u32,
+		) -> DispatchResult {
+			T::ChannelManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				Channels::<T>::get(channel) == Some(ChannelStatus::Pending),
+				Error::<T>::ChannelNotPending
+			);
+			Channels::<T>::remove(channel);
+			PendingChannels::<T>::mutate(|pending| pending.retain(|c| *c != channel));
+
+			Self::send_to_relay(HrmpCall::CancelOpenRequest {
+				channel_id: channel,
+				open_requests,
+			})?;
+			Self::deposit_event(Event::OpenRequestCancelled { channel });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Tracks `channel` as pending, if it is a new channel with another parachain.
+	fn add_pending_channel(channel: HrmpChannelId) -> DispatchResult {
+		ensure!(channel.sender != channel.recipient, Error::<T>::InvalidParachain);
+		ensure!(!Channels::<T>::contains_key(channel), Error::<T>::ChannelExists);
+
+		PendingChannels::<T>::try_append(channel)
+			.map_err(|_| Error::<T>::TooManyPendingChannels)?;
+		Channels::<T>::insert(channel, ChannelStatus::Pending);
+		Ok(())
+	}
+
+	/// The message dispatching `call` on the relay chain, paid for from the sovereign account of
+	/// this parachain, where the fees left are deposited back.
+	pub fn relay_message(call: HrmpCall) -> Xcm<()> {
+		let fees: MultiAsset = (Here, T::RelayFee::get()).into();
+		let self_location = Parachain(T::SelfParaId::get().into());
+		Xcm(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: T::RelayCallWeight::get(),
+				call: (T::HrmpPalletIndex::get(), call).encode().into(),
+			},
+			RefundSurplus,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: self_location.into() },
+		])
+	}
+
+	fn send_to_relay(call: HrmpCall) -> DispatchResult {
+		send_xcm::<T::XcmSender>(MultiLocation::parent(), Self::relay_message(call))
+			.map_err(|_| Error::<T>::SendFailure)?;
+		Ok(())
+	}
+}
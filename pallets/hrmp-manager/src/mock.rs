@@ -0,0 +1,133 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+pub(crate) use crate as pallet_hrmp_manager;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, ConstU8},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use xcm::latest::{MultiAssets, SendError, SendResult, XcmHash};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const SELF_PARA_ID: u32 = 2058;
+pub(crate) const SIBLING_PARA_ID: u32 = 1000;
+pub(crate) const HRMP_PALLET_INDEX: u8 = 60;
+pub(crate) const RELAY_FEE: u128 = 1_000_000_000;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub struct Test {
+		System: frame_system::{Pallet, Call, Event<T>},
+		HrmpManager: pallet_hrmp_manager,
+	}
+);
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub SelfParaId: ParaId = SELF_PARA_ID.into();
+	pub const RelayFee: u128 = RELAY_FEE;
+	pub RelayCallWeight: Weight = Weight::from_parts(1_000_000_000, 200_000);
+	pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+	pub static RelayUnreachable: bool = false;
+	pub static OpenOnRelay: Vec<HrmpChannelId> = vec![];
+}
+
+/// Records sent messages in `SentXcm`, unless `RelayUnreachable` is set.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let msg = msg.take().ok_or(SendError::MissingArgument)?;
+		if RelayUnreachable::get() {
+			return Err(SendError::Unroutable);
+		}
+		Ok(((dest, msg), MultiAssets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+		SentXcm::mutate(|sent| sent.push(ticket));
+		Ok(hash)
+	}
+}
+
+/// Channels are open once added to `OpenOnRelay`.
+pub struct TestRelayChannels;
+impl RelayChannels for TestRelayChannels {
+	fn is_open(channel: &HrmpChannelId) -> bool {
+		OpenOnRelay::get().contains(channel)
+	}
+}
+
+impl pallet_hrmp_manager::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ChannelManagerOrigin = EnsureRoot<u64>;
+	type XcmSender = TestSendXcm;
+	type SelfParaId = SelfParaId;
+	type RelayChannels = TestRelayChannels;
+	type HrmpPalletIndex = ConstU8<HRMP_PALLET_INDEX>;
+	type RelayFee = RelayFee;
+	type RelayCallWeight = RelayCallWeight;
+	type MaxPendingChannels = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities =
+		RuntimeGenesisConfig::default().build_storage().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,357 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Hooks};
+use xcm::latest::{Instruction, MultiAsset};
+
+fn outbound() -> HrmpChannelId {
+	HrmpChannelId { sender: SELF_PARA_ID.into(), recipient: SIBLING_PARA_ID.into() }
+}
+
+fn inbound() -> HrmpChannelId {
+	HrmpChannelId { sender: SIBLING_PARA_ID.into(), recipient: SELF_PARA_ID.into() }
+}
+
+/// The encoded relay chain call of the only message sent.
+fn sent_relay_call() -> Vec<u8> {
+	let sent = SentXcm::get();
+	assert_eq!(sent.len(), 1);
+	assert_eq!(sent[0].0, MultiLocation::parent());
+	match &sent[0].1 .0[2] {
+		Instruction::Transact { call, .. } => call.clone().into_encoded(),
+		instruction => panic!("unexpected instruction {:?}", instruction),
+	}
+}
+
+fn request_outbound() {
+	assert_ok!(HrmpManager::request_open_channel(
+		RuntimeOrigin::root(),
+		SIBLING_PARA_ID.into(),
+		8,
+		8192
+	));
+}
+
+#[test]
+fn request_open_channel_works() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Pending));
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![outbound()]);
+		System::assert_last_event(
+			Event::OpenChannelRequested {
+				recipient: SIBLING_PARA_ID.into(),
+				max_capacity: 8,
+				max_message_size: 8192,
+			}
+			.into(),
+		);
+		assert_eq!(
+			sent_relay_call(),
+			(HRMP_PALLET_INDEX, 0u8, SIBLING_PARA_ID, 8u32, 8192u32).encode()
+		);
+	});
+}
+
+#[test]
+fn relay_message_is_paid_by_sovereign_account() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+
+		let fees: MultiAsset = (Here, RELAY_FEE).into();
+		let message = &SentXcm::get()[0].1;
+		assert_eq!(message.0[0], WithdrawAsset(fees.clone().into()));
+		assert_eq!(message.0[1], BuyExecution { fees, weight_limit: Unlimited });
+		assert!(matches!(
+			message.0[2],
+			Transact { origin_kind: OriginKind::Native, require_weight_at_most, .. }
+				if require_weight_at_most == RelayCallWeight::get()
+		));
+		assert_eq!(message.0[3], RefundSurplus);
+		assert_eq!(
+			message.0[4],
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: Parachain(SELF_PARA_ID).into()
+			}
+		);
+	});
+}
+
+#[test]
+fn accept_open_channel_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), SIBLING_PARA_ID.into()));
+
+		assert_eq!(HrmpManager::channel(inbound()), Some(ChannelStatus::Pending));
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![inbound()]);
+		System::assert_last_event(
+			Event::OpenChannelAccepted { sender: SIBLING_PARA_ID.into() }.into(),
+		);
+		assert_eq!(sent_relay_call(), (HRMP_PALLET_INDEX, 1u8, SIBLING_PARA_ID).encode());
+	});
+}
+
+#[test]
+fn pending_channels_open_with_relay_chain() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+		assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), SIBLING_PARA_ID.into()));
+
+		// Nothing is open on the relay chain yet
+		HrmpManager::on_initialize(2);
+		assert_eq!(HrmpManager::pending_channels().len(), 2);
+
+		OpenOnRelay::set(vec![outbound()]);
+		HrmpManager::on_initialize(3);
+
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Open));
+		assert_eq!(HrmpManager::channel(inbound()), Some(ChannelStatus::Pending));
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![inbound()]);
+		System::assert_last_event(Event::ChannelOpened { channel: outbound() }.into());
+	});
+}
+
+#[test]
+fn close_channel_works() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+		OpenOnRelay::set(vec![outbound()]);
+		HrmpManager::on_initialize(2);
+		SentXcm::set(vec![]);
+
+		assert_ok!(HrmpManager::close_channel(RuntimeOrigin::root(), outbound()));
+
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Closing));
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![outbound()]);
+		System::assert_last_event(Event::CloseChannelRequested { channel: outbound() }.into());
+		assert_eq!(
+			sent_relay_call(),
+			(HRMP_PALLET_INDEX, 2u8, SELF_PARA_ID, SIBLING_PARA_ID).encode()
+		);
+	});
+}
+
+#[test]
+fn closing_channels_close_with_relay_chain() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+		OpenOnRelay::set(vec![outbound()]);
+		HrmpManager::on_initialize(2);
+		assert_ok!(HrmpManager::close_channel(RuntimeOrigin::root(), outbound()));
+
+		// The relay chain closes the channel at the next session only
+		HrmpManager::on_initialize(3);
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Closing));
+		assert_noop!(
+			HrmpManager::close_channel(RuntimeOrigin::root(), outbound()),
+			Error::<Test>::ChannelClosing
+		);
+		assert_noop!(
+			HrmpManager::request_open_channel(
+				RuntimeOrigin::root(),
+				SIBLING_PARA_ID.into(),
+				8,
+				8192
+			),
+			Error::<Test>::ChannelExists
+		);
+
+		OpenOnRelay::set(vec![]);
+		HrmpManager::on_initialize(4);
+
+		assert_eq!(HrmpManager::channel(outbound()), None);
+		assert!(HrmpManager::pending_channels().is_empty());
+		System::assert_last_event(Event::ChannelClosed { channel: outbound() }.into());
+		// The channel can be requested again
+		request_outbound();
+	});
+}
+
+#[test]
+fn close_channel_forgets_pending_channels() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+
+		assert_ok!(HrmpManager::close_channel(RuntimeOrigin::root(), outbound()));
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Closing));
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![outbound()]);
+
+		// The channel was never opened on the relay chain
+		HrmpManager::on_initialize(2);
+		assert_eq!(HrmpManager::channel(outbound()), None);
+		assert!(HrmpManager::pending_channels().is_empty());
+		// The channel can be requested again
+		request_outbound();
+	});
+}
+
+#[test]
+fn cancel_open_request_works() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+		assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), SIBLING_PARA_ID.into()));
+		SentXcm::set(vec![]);
+
+		assert_ok!(HrmpManager::cancel_open_request(RuntimeOrigin::root(), outbound(), 3));
+
+		assert_eq!(HrmpManager::channel(outbound()), None);
+		assert_eq!(HrmpManager::pending_channels().to_vec(), vec![inbound()]);
+		System::assert_last_event(Event::OpenRequestCancelled { channel: outbound() }.into());
+		assert_eq!(
+			sent_relay_call(),
+			(HRMP_PALLET_INDEX, 6u8, SELF_PARA_ID, SIBLING_PARA_ID, 3u32).encode()
+		);
+
+		// The channel can be requested again
+		SentXcm::set(vec![]);
+		request_outbound();
+		assert_eq!(HrmpManager::channel(outbound()), Some(ChannelStatus::Pending));
+	});
+}
+
+#[test]
+fn cancel_open_request_only_cancels_pending_channels() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpManager::cancel_open_request(RuntimeOrigin::root(), outbound(), 1),
+			Error::<Test>::ChannelNotPending
+		);
+
+		request_outbound();
+		OpenOnRelay::set(vec![outbound()]);
+		HrmpManager::on_initialize(2);
+		assert_noop!(
+			HrmpManager::cancel_open_request(RuntimeOrigin::root(), outbound(), 1),
+			Error::<Test>::ChannelNotPending
+		);
+		assert_noop!(
+			HrmpManager::cancel_open_request(RuntimeOrigin::signed(1), outbound(), 1),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn close_channel_works_for_untracked_channels() {
+	new_test_ext().execute_with(|| {
+		OpenOnRelay::set(vec![inbound()]);
+		assert_ok!(HrmpManager::close_channel(RuntimeOrigin::root(), inbound()));
+		assert_eq!(
+			sent_relay_call(),
+			(HRMP_PALLET_INDEX, 2u8, SIBLING_PARA_ID, SELF_PARA_ID).encode()
+		);
+		assert_eq!(HrmpManager::channel(inbound()), Some(ChannelStatus::Closing));
+
+		OpenOnRelay::set(vec![]);
+		HrmpManager::on_initialize(2);
+		assert_eq!(HrmpManager::channel(inbound()), None);
+		System::assert_last_event(Event::ChannelClosed { channel: inbound() }.into());
+	});
+}
+
+#[test]
+fn close_channel_is_limited_by_pending_channels() {
+	new_test_ext().execute_with(|| {
+		request_outbound();
+		assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), SIBLING_PARA_ID.into()));
+
+		let channel = HrmpChannelId { sender: SELF_PARA_ID.into(), recipient: 2000.into() };
+		assert_noop!(
+			HrmpManager::close_channel(RuntimeOrigin::root(), channel),
+			Error::<Test>::TooManyPendingChannels
+		);
+		// Closing a pending channel takes no more room
+		assert_ok!(HrmpManager::close_channel(RuntimeOrigin::root(), outbound()));
+	});
+}
+
+#[test]
+fn close_channel_rejects_channels_of_others() {
+	new_test_ext().execute_with(|| {
+		let channel = HrmpChannelId { sender: SIBLING_PARA_ID.into(), recipient: 2000.into() };
+		assert_noop!(
+			HrmpManager::close_channel(RuntimeOrigin::root(), channel),
+			Error::<Test>::NotOwnChannel
+		);
+	});
+}
+
+#[test]
+fn open_channel_rejects_invalid_channels() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpManager::request_open_channel(RuntimeOrigin::root(), SELF_PARA_ID.into(), 8, 8192),
+			Error::<Test>::InvalidParachain
+		);
+
+		request_outbound();
+		assert_noop!(
+			HrmpManager::request_open_channel(
+				RuntimeOrigin::root(),
+				SIBLING_PARA_ID.into(),
+				8,
+				8192
+			),
+			Error::<Test>::ChannelExists
+		);
+
+		assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), SIBLING_PARA_ID.into()));
+		assert_noop!(
+			HrmpManager::accept_open_channel(RuntimeOrigin::root(), 2000.into()),
+			Error::<Test>::TooManyPendingChannels
+		);
+	});
+}
+
+#[test]
+fn open_channel_fails_if_relay_chain_is_unreachable() {
+	new_test_ext().execute_with(|| {
+		RelayUnreachable::set(true);
+		assert_noop!(
+			HrmpManager::request_open_channel(
+				RuntimeOrigin::root(),
+				SIBLING_PARA_ID.into(),
+				8,
+				8192
+			),
+			Error::<Test>::SendFailure
+		);
+	});
+}
+
+#[test]
+fn channels_are_managed_by_manager_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpManager::request_open_channel(
+				RuntimeOrigin::signed(1),
+				SIBLING_PARA_ID.into(),
+				8,
+				8192
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			HrmpManager::accept_open_channel(RuntimeOrigin::signed(1), SIBLING_PARA_ID.into()),
+			BadOrigin
+		);
+		assert_noop!(HrmpManager::close_channel(RuntimeOrigin::signed(1), outbound()), BadOrigin);
+	});
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use cumulus_primitives_core::ParaId;
+use frame_support::pallet_prelude::*;
+
+/// An HRMP channel, from `sender` to `recipient`, encoded like `HrmpChannelId` on the relay chain.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HrmpChannelId {
+	pub sender: ParaId,
+	pub recipient: ParaId,
+}
+
+/// Status of an HRMP channel of this chain.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChannelStatus {
+	/// Opening the channel was requested or accepted by this chain. The relay chain opens it at
+	/// the next session once both sides did.
+	Pending,
+	/// The channel is open.
+	Open,
+	/// Closing the channel was requested by this chain. The relay chain closes it at the next
+	/// session.
+	Closing,
+}
+
+/// Calls of the `hrmp` pallet of the relay chain.
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum HrmpCall {
+	#[codec(index = 0)]
+	InitOpenChannel {
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	},
+	#[codec(index = 1)]
+	AcceptOpenChannel { sender: ParaId },
+	#[codec(index = 2)]
+	CloseChannel { channel_id: HrmpChannelId },
+	#[codec(index = 6)]
+	CancelOpenRequest { channel_id: HrmpChannelId, open_requests: u32 },
+}
+
+/// The HRMP channels open on the relay chain, as last seen by this chain.
+pub trait RelayChannels {
+	/// Returns true if `channel` is open.
+	fn is_open(channel: &HrmpChannelId) -> bool;
+}
+
+impl RelayChannels for () {
+	fn is_open(_channel: &HrmpChannelId) -> bool {
+		false
+	}
+}
@@ -0,0 +1,34 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API to query the history of motions executed by `pallet_motion`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_motion::types::ExecutedMotion;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait MotionApi<Hash, BlockNumber>
+	where
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Executed motions still in the motion history, oldest first.
+		fn executed_motions() -> Vec<ExecutedMotion<Hash, BlockNumber>>;
+	}
+}
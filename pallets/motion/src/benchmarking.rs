@@ -0,0 +1,173 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks of the overhead of motions. The dispatched calls are remarks, their own weight is
+//! added by the pallet.
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{
+	traits::{Get, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+use super::{CancellationExpiries, CancelledMotions, Pallet as Motion, PendingMotions};
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn remark_call<T: Config>() -> <T as Config>::RuntimeCall
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	frame_system::Call::<T>::remark { remark: vec![] }.into()
+}
+
+/// Queues a remark motion, executable right away.
+fn queue_motion<T: Config>() -> (T::Hash, Weight)
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	let call = MotionCall::<T>::Single(Box::new(remark_call::<T>()));
+	let motion_hash =
+		Motion::<T>::motion_hash(&call, MajorityLevel::SimpleMajority, DispatchAs::Root);
+	let weight = call.weight();
+	let class = call.class();
+	PendingMotions::<T>::insert(
+		motion_hash,
+		PendingMotion {
+			call,
+			level: MajorityLevel::SimpleMajority,
+			dispatch_as: DispatchAs::Root,
+			ready_at: frame_system::Pallet::<T>::block_number(),
+			class,
+			proposal_index: None,
+		},
+	);
+	(motion_hash, weight)
+}
+
+/// Records `count` cancelled motions whose reasons expire at `expires_at`.
+fn fill_cancellations<T: Config>(count: u32, expires_at: BlockNumberFor<T>) {
+	let expiries: Vec<_> = (0..count).map(|i| (expires_at, T::Hashing::hash_of(&i))).collect();
+	for (_, motion_hash) in &expiries {
+		CancelledMotions::<T>::insert(motion_hash, BoundedVec::default());
+	}
+	CancellationExpiries::<T>::put(BoundedVec::truncate_from(expiries));
+}
+
+benchmarks! {
+	where_clause { where <T as Config>::RuntimeCall: From<frame_system::Call<T>> }
+
+	simple_majority {
+		let origin =
+			T::SimpleMajorityOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Box::new(remark_call::<T>());
+	}: _<T::RuntimeOrigin>(origin, call)
+
+	super_majority {
+		let origin =
+			T::SuperMajorityOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Box::new(remark_call::<T>());
+	}: _<T::RuntimeOrigin>(origin, call)
+
+	unanimous {
+		let origin =
+			T::UnanimousOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Box::new(remark_call::<T>());
+	}: _<T::RuntimeOrigin>(origin, call)
+
+	execute_ready {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let (motion_hash, weight) = queue_motion::<T>();
+	}: _(SystemOrigin::Signed(caller), motion_hash, weight)
+	verify {
+		assert!(PendingMotions::<T>::get(motion_hash).is_none());
+		assert_last_event::<T>(Event::MotionExecuted { motion_hash, motion_result: Ok(()) }.into());
+	}
+
+	cancel {
+		let origin =
+			T::VetoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (motion_hash, _) = queue_motion::<T>();
+		// The oldest reason is removed to make room for the new one
+		fill_cancellations::<T>(T::MaxHistoryLength::get(), 1u32.into());
+		let reason: BoundedVec<u8, T::MaxReasonLength> =
+			BoundedVec::truncate_from(vec![0u8; T::MaxReasonLength::get() as usize]);
+	}: _<T::RuntimeOrigin>(origin, motion_hash, reason.clone())
+	verify {
+		assert!(PendingMotions::<T>::get(motion_hash).is_none());
+		assert_last_event::<T>(Event::MotionCancelled { motion_hash, reason }.into());
+	}
+
+	dispatch_as_motion_origin {
+		let origin =
+			T::UnanimousOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Box::new(remark_call::<T>());
+	}: _<T::RuntimeOrigin>(origin, MajorityLevel::Unanimous, call)
+
+	batch {
+		let c in 0 .. T::MaxBatchCalls::get();
+		let origin =
+			T::UnanimousOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let calls: Vec<<T as Config>::RuntimeCall> = (0..c).map(|_| remark_call::<T>()).collect();
+	}: _<T::RuntimeOrigin>(origin, MajorityLevel::Unanimous, DispatchAs::Root, calls, true)
+
+	on_initialize {
+		let c in 0 .. T::MaxHistoryLength::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		fill_cancellations::<T>(c, now);
+	}: {
+		Motion::<T>::on_initialize(now);
+	}
+	verify {
+		assert!(CancellationExpiries::<T>::get().is_empty());
+	}
+
+	fast_track {
+		let origin =
+			T::FastTrackOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// Runtime upgrades are the calls meant to be fast-tracked
+		let call = MotionCall::<T>::Single(Box::new(
+			frame_system::Call::<T>::set_code { code: vec![] }.into(),
+		));
+		let motion_hash =
+			Motion::<T>::motion_hash(&call, MajorityLevel::SuperMajority, DispatchAs::Root);
+		let ready_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let class = call.class();
+		PendingMotions::<T>::insert(
+			motion_hash,
+			PendingMotion {
+				call,
+				level: MajorityLevel::SuperMajority,
+				dispatch_as: DispatchAs::Root,
+				ready_at,
+				class,
+				proposal_index: None,
+			},
+		);
+	}: _<T::RuntimeOrigin>(origin, motion_hash)
+	verify {
+		let ready_at = frame_system::Pallet::<T>::block_number();
+		let motion = PendingMotions::<T>::get(motion_hash).expect("motion is still queued");
+		assert_eq!(motion.ready_at, ready_at);
+		assert_last_event::<T>(Event::MotionFastTracked { motion_hash, ready_at }.into());
+	}
+}
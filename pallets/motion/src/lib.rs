@@ -20,11 +20,25 @@
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod types;
+pub mod weights;
 
-use sp_runtime::DispatchResult;
-use sp_std::prelude::*;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use crate::types::{
+	DispatchAs, ExecutedMotion, MajorityLevel, MotionCall, PendingMotion, RawOrigin,
+};
+use frame_support::traits::{EnsureOrigin, Get};
+use sp_runtime::{
+	traits::{Convert, Hash, Zero},
+	DispatchResult,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
 pub use pallet::*;
+pub use pallet_collective::ProposalIndex;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -32,7 +46,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
-	use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable};
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		storage::{with_transaction, TransactionOutcome},
+		traits::{Contains, UnfilteredDispatchable},
+	};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -44,13 +62,116 @@ pub mod pallet {
 
 		type RuntimeCall: Parameter
 			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
-			+ GetDispatchInfo;
+			+ GetDispatchInfo
+			+ From<Call<Self>>;
 
 		type SimpleMajorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type SuperMajorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type UnanimousOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Delay between the approval of a simple majority motion and its execution.
+		#[pallet::constant]
+		type SimpleMajorityDelay: Get<BlockNumberFor<Self>>;
+
+		/// Delay between the approval of a super majority motion and its execution.
+		#[pallet::constant]
+		type SuperMajorityDelay: Get<BlockNumberFor<Self>>;
+
+		/// Delay between the approval of a unanimous motion and its execution.
+		#[pallet::constant]
+		type UnanimousDelay: Get<BlockNumberFor<Self>>;
+
+		/// Calls a simple majority motion is allowed to dispatch.
+		type SimpleMajorityCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Calls a super majority motion is allowed to dispatch.
+		type SuperMajorityCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Calls a unanimous motion is allowed to dispatch.
+		type UnanimousCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Origin allowed to cancel a queued motion.
+		type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum length of the reason given when cancelling a motion.
+		#[pallet::constant]
+		type MaxReasonLength: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The maximum number of calls in a batch motion.
+		#[pallet::constant]
+		type MaxBatchCalls: Get<u32>;
+
+		/// Origin a `dispatch_as_motion_origin` motion dispatches its call with, by majority
+		/// level. [`AsMotionOrigin`] uses the custom [`Origin`] of this pallet.
+		type MotionOrigin: Convert<MajorityLevel, <Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Looks up the collective proposal a motion was approved by, to record it in the
+		/// motion history.
+		type CollectiveProposals: ProposalIndexOf<Self::Hash>;
+
+		/// The maximum number of executed motions kept in the motion history.
+		///
+		/// The history is a ring buffer indexed by `ExecutedCount % MaxHistoryLength`. Changing
+		/// this value without clearing the history with [`Pallet::clear_motion_history`] leaves
+		/// entries in the wrong slots.
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+
+		/// Origin allowed to fast-track a queued motion, making it executable right away.
+		type FastTrackOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Calls a queued motion must be limited to for it to be fast-tracked, such as runtime
+		/// upgrades.
+		type FastTrackCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Number of blocks the reason of a cancelled motion is kept for. At most
+		/// `MaxHistoryLength` reasons are kept, the oldest one is removed early to make room.
+		#[pallet::constant]
+		type CancellationRetention: Get<BlockNumberFor<Self>>;
 	}
 
+	/// Origin of the calls dispatched by a motion with the custom origin of this pallet.
+	#[pallet::origin]
+	pub type Origin = RawOrigin;
+
+	/// Approved motions waiting for their delay to pass, by motion hash (see
+	/// [`Pallet::motion_hash`]).
+	#[pallet::storage]
+	#[pallet::getter(fn pending_motion)]
+	#[pallet::unbounded]
+	pub type PendingMotions<T: Config> = StorageMap<_, Identity, T::Hash, PendingMotion<T>>;
+
+	/// Reason given for cancelling a queued motion, by motion hash. Removed once it expires, see
+	/// `CancellationExpiries`.
+	#[pallet::storage]
+	#[pallet::getter(fn cancellation_reason)]
+	pub type CancelledMotions<T: Config> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<u8, T::MaxReasonLength>>;
+
+	/// Motions in `CancelledMotions` with the block their reason expires at, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn cancellation_expiries)]
+	pub type CancellationExpiries<T: Config> =
+		StorageValue<_, BoundedVec<(BlockNumberFor<T>, T::Hash), T::MaxHistoryLength>, ValueQuery>;
+
+	/// Ring buffer of the last `MaxHistoryLength` executed motions. Motion number `n` is stored
+	/// in slot `n % MaxHistoryLength`.
+	///
+	/// Changing `MaxHistoryLength` moves every motion to a different slot, so the existing
+	/// entries must be cleared by a migration along with the change, see
+	/// [`Pallet::clear_motion_history`].
+	#[pallet::storage]
+	pub type MotionHistory<T: Config> =
+		StorageMap<_, Twox64Concat, u32, ExecutedMotion<T::Hash, BlockNumberFor<T>>>;
+
+	/// Number of motions executed since the motion history was introduced.
+	#[pallet::storage]
+	#[pallet::getter(fn executed_count)]
+	pub type ExecutedCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -60,10 +181,57 @@ pub mod pallet {
 		DispatchSuperMajority { motion_result: DispatchResult },
 		/// A Unanimous motion was executed. motion_result contains the call result
 		DispatchUnanimous { motion_result: DispatchResult },
+		/// A motion was approved and will be executable from block `ready_at`.
+		MotionQueued { motion_hash: T::Hash, level: MajorityLevel, ready_at: BlockNumberFor<T> },
+		/// A queued motion was executed. motion_result contains the call result
+		MotionExecuted { motion_hash: T::Hash, motion_result: DispatchResult },
+		/// A queued motion was cancelled before being executed.
+		MotionCancelled { motion_hash: T::Hash, reason: BoundedVec<u8, T::MaxReasonLength> },
+		/// A motion was executed with the motion origin of `level`. motion_result contains the
+		/// call result
+		DispatchMotionOrigin { level: MajorityLevel, motion_result: DispatchResult },
+		/// A batch motion was executed. results contains the result of each dispatched call. If
+		/// `rolled_back`, the batch stopped at the first failing call and none of its calls had
+		/// any effect.
+		BatchDispatched { level: MajorityLevel, results: Vec<DispatchResult>, rolled_back: bool },
+		/// A queued motion was fast-tracked and is executable from block `ready_at`.
+		MotionFastTracked { motion_hash: T::Hash, ready_at: BlockNumberFor<T> },
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// The same call is already waiting to be executed
+		MotionAlreadyQueued,
+		/// There is no queued motion with the given hash
+		MotionNotFound,
+		/// The delay of the motion has not passed yet
+		MotionNotReady,
+		/// The weight bound is lower than the weight of the motion call
+		WrongWeightBound,
+		/// The call requires a higher majority
+		CallNotAllowed,
+		/// The batch contains more calls than `MaxBatchCalls`
+		TooManyCalls,
+		/// The motion dispatches calls that can not be fast-tracked
+		CannotFastTrack,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Removes the cancellation reasons expiring at `now`.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut expiries = CancellationExpiries::<T>::get().into_inner();
+			let expired = expiries.iter().take_while(|(expires_at, _)| *expires_at <= now).count();
+			if expired > 0 {
+				let remaining = expiries.split_off(expired);
+				for (_, motion_hash) in expiries {
+					CancelledMotions::<T>::remove(motion_hash);
+				}
+				CancellationExpiries::<T>::put(BoundedVec::truncate_from(remaining));
+			}
+			T::WeightInfo::on_initialize(expired as u32)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -73,11 +241,12 @@ pub mod pallet {
 		/// - O(1).
 		/// - Limited storage reads.
 		/// - One DB write (event).
-		/// - Weight of derivative `call` execution + 10,000.
+		/// - Weight of derivative `call` execution + `WeightInfo::simple_majority`.
 		/// # </weight>
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(dispatch_info.weight, dispatch_info.class)
+			let weight = T::WeightInfo::simple_majority().saturating_add(dispatch_info.weight);
+			(weight, dispatch_info.class)
 		})]
 		#[pallet::call_index(0)]
 		pub fn simple_majority(
@@ -86,10 +255,13 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::SimpleMajorityOrigin::ensure_origin(origin)?;
 
-			let motion_result = Self::do_dispatch(call);
-			Self::deposit_event(Event::DispatchSimpleMajority { motion_result });
+			let weight = Self::do_motion(
+				MotionCall::Single(call),
+				MajorityLevel::SimpleMajority,
+				DispatchAs::Root,
+			)?;
 
-			Ok(Pays::No.into())
+			Self::motion_post_info(T::WeightInfo::simple_majority(), weight)
 		}
 
 		/// Ensures the super majority is met and dispatches a call with `Root` origin.
@@ -98,11 +270,12 @@ pub mod pallet {
 		/// - O(1).
 		/// - Limited storage reads.
 		/// - One DB write (event).
-		/// - Weight of derivative `call` execution + 10,000.
+		/// - Weight of derivative `call` execution + `WeightInfo::super_majority`.
 		/// # </weight>
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(dispatch_info.weight, dispatch_info.class)
+			let weight = T::WeightInfo::super_majority().saturating_add(dispatch_info.weight);
+			(weight, dispatch_info.class)
 		})]
 		#[pallet::call_index(1)]
 		pub fn super_majority(
@@ -111,10 +284,13 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::SuperMajorityOrigin::ensure_origin(origin)?;
 
-			let motion_result = Self::do_dispatch(call);
-			Self::deposit_event(Event::DispatchSuperMajority { motion_result });
+			let weight = Self::do_motion(
+				MotionCall::Single(call),
+				MajorityLevel::SuperMajority,
+				DispatchAs::Root,
+			)?;
 
-			Ok(Pays::No.into())
+			Self::motion_post_info(T::WeightInfo::super_majority(), weight)
 		}
 
 		/// Ensures unanimous voting is met and dispatches a call with `Root` origin.
@@ -123,11 +299,12 @@ pub mod pallet {
 		/// - O(1).
 		/// - Limited storage reads.
 		/// - One DB write (event).
-		/// - Weight of derivative `call` execution + 10,000.
+		/// - Weight of derivative `call` execution + `WeightInfo::unanimous`.
 		/// # </weight>
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(dispatch_info.weight, dispatch_info.class)
+			let weight = T::WeightInfo::unanimous().saturating_add(dispatch_info.weight);
+			(weight, dispatch_info.class)
 		})]
 		#[pallet::call_index(2)]
 		pub fn unanimous(
@@ -136,23 +313,469 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::UnanimousOrigin::ensure_origin(origin)?;
 
-			let motion_result = Self::do_dispatch(call);
-			Self::deposit_event(Event::DispatchUnanimous { motion_result });
+			let weight = Self::do_motion(
+				MotionCall::Single(call),
+				MajorityLevel::Unanimous,
+				DispatchAs::Root,
+			)?;
 
-			Ok(Pays::No.into())
+			Self::motion_post_info(T::WeightInfo::unanimous(), weight)
+		}
+
+		/// Executes a queued motion whose delay has passed.
+		///
+		/// Can be called by any signed origin. `weight_bound` must be at least the weight of the
+		/// motion call. The call is dispatched with the class of the queued motion calls, so
+		/// `Operational` motions can still be executed in a full block.
+		#[pallet::weight({
+			let weight = T::WeightInfo::execute_ready().saturating_add(*weight_bound);
+			(weight, Pallet::<T>::pending_motion_class(motion_hash))
+		})]
+		#[pallet::call_index(3)]
+		pub fn execute_ready(
+			origin: OriginFor<T>,
+			motion_hash: T::Hash,
+			weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let motion = PendingMotions::<T>::get(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= motion.ready_at,
+				Error::<T>::MotionNotReady
+			);
+			ensure!(motion.call.weight().all_lte(weight_bound), Error::<T>::WrongWeightBound);
+
+			PendingMotions::<T>::remove(motion_hash);
+			let (motion_result, weight) =
+				Self::do_dispatch(motion.call, motion.level, motion.dispatch_as)?;
+			Self::record_execution(motion_hash, motion.level, motion_result, motion.proposal_index);
+			Self::deposit_event(Event::MotionExecuted { motion_hash, motion_result });
+
+			Ok(Some(T::WeightInfo::execute_ready().saturating_add(weight)).into())
+		}
+
+		/// Cancels a queued motion before it is executed, recording `reason` for
+		/// `CancellationRetention` blocks.
+		#[pallet::weight(T::WeightInfo::cancel())]
+		#[pallet::call_index(4)]
+		pub fn cancel(
+			origin: OriginFor<T>,
+			motion_hash: T::Hash,
+			reason: BoundedVec<u8, T::MaxReasonLength>,
+		) -> DispatchResult {
+			T::VetoOrigin::ensure_origin(origin)?;
+			ensure!(PendingMotions::<T>::contains_key(motion_hash), Error::<T>::MotionNotFound);
+
+			PendingMotions::<T>::remove(motion_hash);
+			Self::record_cancellation(motion_hash, reason.clone());
+			Self::deposit_event(Event::MotionCancelled { motion_hash, reason });
+
+			Ok(())
+		}
+
+		/// Ensures the majority `level` is met and dispatches a call with the origin configured
+		/// for `level` by `MotionOrigin` instead of `Root`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB write (event).
+		/// - Weight of derivative `call` execution + `WeightInfo::dispatch_as_motion_origin`.
+		/// # </weight>
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			let weight =
+				T::WeightInfo::dispatch_as_motion_origin().saturating_add(dispatch_info.weight);
+			(weight, dispatch_info.class)
+		})]
+		#[pallet::call_index(5)]
+		pub fn dispatch_as_motion_origin(
+			origin: OriginFor<T>,
+			level: MajorityLevel,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_majority(origin, level)?;
+
+			let weight =
+				Self::do_motion(MotionCall::Single(call), level, DispatchAs::MotionOrigin)?;
+
+			Self::motion_post_info(T::WeightInfo::dispatch_as_motion_origin(), weight)
+		}
+
+		/// Ensures the majority `level` is met and dispatches `calls` in order, with `Root`
+		/// origin or the motion origin of `level` depending on `dispatch_as`.
+		///
+		/// If `atomic`, the first failing call reverts the whole batch. Otherwise every call is
+		/// dispatched whatever the result of the previous ones. The result of each dispatched call
+		/// is reported in the `BatchDispatched` event.
+		#[pallet::weight({
+			let weight = calls.iter().fold(T::WeightInfo::batch(calls.len() as u32), |total, call| {
+				total.saturating_add(call.get_dispatch_info().weight)
+			});
+			(weight, MotionCall::<T>::batch_class(calls))
+		})]
+		#[pallet::call_index(6)]
+		pub fn batch(
+			origin: OriginFor<T>,
+			level: MajorityLevel,
+			dispatch_as: DispatchAs,
+			calls: Vec<<T as Config>::RuntimeCall>,
+			atomic: bool,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_majority(origin, level)?;
+			ensure!(calls.len() <= T::MaxBatchCalls::get() as usize, Error::<T>::TooManyCalls);
+
+			let calls_len = calls.len() as u32;
+			let weight = Self::do_motion(MotionCall::Batch { calls, atomic }, level, dispatch_as)?;
+
+			Self::motion_post_info(T::WeightInfo::batch(calls_len), weight)
+		}
+
+		/// Makes a queued motion executable from the current block, skipping the rest of its
+		/// delay. Only motions whose calls all match `FastTrackCalls` can be fast-tracked.
+		///
+		/// The motion is then executed with `execute_ready`, and can still be cancelled until
+		/// it is.
+		#[pallet::weight(T::WeightInfo::fast_track())]
+		#[pallet::call_index(7)]
+		pub fn fast_track(origin: OriginFor<T>, motion_hash: T::Hash) -> DispatchResult {
+			T::FastTrackOrigin::ensure_origin(origin)?;
+
+			let mut motion =
+				PendingMotions::<T>::get(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
+			let allowed = match &motion.call {
+				MotionCall::Single(call) => T::FastTrackCalls::contains(call),
+				MotionCall::Batch { calls, .. } => calls.iter().all(T::FastTrackCalls::contains),
+			};
+			ensure!(allowed, Error::<T>::CannotFastTrack);
+
+			let ready_at = frame_system::Pallet::<T>::block_number();
+			motion.ready_at = ready_at;
+			PendingMotions::<T>::insert(motion_hash, motion);
+			Self::deposit_event(Event::MotionFastTracked { motion_hash, ready_at });
+
+			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		/// Helper to actually dispatch RuntimeCall.
+		/// Post dispatch info of a motion: its own `overhead` plus the actual weight of the
+		/// dispatched calls. Motions are free.
+		fn motion_post_info(overhead: Weight, dispatched: Weight) -> DispatchResultWithPostInfo {
+			Ok(PostDispatchInfo {
+				actual_weight: Some(overhead.saturating_add(dispatched)),
+				pays_fee: Pays::No,
+			})
+		}
+
+		/// Ensures `origin` is the origin of the majority `level`.
+		fn ensure_majority(origin: OriginFor<T>, level: MajorityLevel) -> DispatchResult {
+			match level {
+				MajorityLevel::SimpleMajority => {
+					T::SimpleMajorityOrigin::ensure_origin(origin)?;
+				},
+				MajorityLevel::SuperMajority => {
+					T::SuperMajorityOrigin::ensure_origin(origin)?;
+				},
+				MajorityLevel::Unanimous => {
+					T::UnanimousOrigin::ensure_origin(origin)?;
+				},
+			}
+			Ok(())
+		}
+
+		/// Dispatches the calls right away if `level` has no delay, queues them otherwise.
+		///
+		/// Returns the weight of the dispatched calls.
+		fn do_motion(
+			call: MotionCall<T>,
+			level: MajorityLevel,
+			dispatch_as: DispatchAs,
+		) -> Result<Weight, DispatchError> {
+			let allowed = match &call {
+				MotionCall::Single(call) => Self::is_call_allowed(call, level),
+				MotionCall::Batch { calls, .. } => {
+					calls.iter().all(|call| Self::is_call_allowed(call, level))
+				},
+			};
+			ensure!(allowed, Error::<T>::CallNotAllowed);
+
+			let motion_hash = Self::motion_hash(&call, level, dispatch_as);
+			let proposal_index = Self::proposal_index(&call, level, dispatch_as);
+			let delay = Self::delay(level);
+			if delay.is_zero() {
+				let is_batch = matches!(call, MotionCall::Batch { .. });
+				let (motion_result, weight) = Self::do_dispatch(call, level, dispatch_as)?;
+				Self::record_execution(motion_hash, level, motion_result, proposal_index);
+				// Batches report the result of each call in their own event
+				if is_batch {
+					return Ok(weight);
+				}
+				let event = match (dispatch_as, level) {
+					(DispatchAs::MotionOrigin, level) => {
+						Event::DispatchMotionOrigin { level, motion_result }
+					},
+					(DispatchAs::Root, MajorityLevel::SimpleMajority) => {
+						Event::DispatchSimpleMajority { motion_result }
+					},
+					(DispatchAs::Root, MajorityLevel::SuperMajority) => {
+						Event::DispatchSuperMajority { motion_result }
+					},
+					(DispatchAs::Root, MajorityLevel::Unanimous) => {
+						Event::DispatchUnanimous { motion_result }
+					},
+				};
+				Self::deposit_event(event);
+				return Ok(weight);
+			}
+
+			ensure!(
+				!PendingMotions::<T>::contains_key(motion_hash),
+				Error::<T>::MotionAlreadyQueued
+			);
+			let ready_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+			let class = call.class();
+			PendingMotions::<T>::insert(
+				motion_hash,
+				PendingMotion { call, level, dispatch_as, ready_at, class, proposal_index },
+			);
+			Self::deposit_event(Event::MotionQueued { motion_hash, level, ready_at });
+			Ok(Weight::zero())
+		}
+
+		/// Index of the collective proposal approving the motion, if the motion is dispatched
+		/// by a proposal that is still open.
+		fn proposal_index(
+			call: &MotionCall<T>,
+			level: MajorityLevel,
+			dispatch_as: DispatchAs,
+		) -> Option<ProposalIndex> {
+			let proposal = match (call.clone(), dispatch_as) {
+				(MotionCall::Single(call), DispatchAs::Root) => match level {
+					MajorityLevel::SimpleMajority => Call::<T>::simple_majority { call },
+					MajorityLevel::SuperMajority => Call::<T>::super_majority { call },
+					MajorityLevel::Unanimous => Call::<T>::unanimous { call },
+				},
+				(MotionCall::Single(call), DispatchAs::MotionOrigin) => {
+					Call::<T>::dispatch_as_motion_origin { level, call }
+				},
+				(MotionCall::Batch { calls, atomic }, dispatch_as) => {
+					Call::<T>::batch { level, dispatch_as, calls, atomic }
+				},
+			};
+			let proposal: <T as Config>::RuntimeCall = proposal.into();
+			T::CollectiveProposals::proposal_index(&T::Hashing::hash_of(&proposal))
+		}
+
+		/// Adds an executed motion to the motion history, overwriting the oldest entry once the
+		/// history is full.
+		fn record_execution(
+			motion_hash: T::Hash,
+			level: MajorityLevel,
+			result: DispatchResult,
+			proposal_index: Option<ProposalIndex>,
+		) {
+			let max_length = T::MaxHistoryLength::get();
+			if max_length.is_zero() {
+				return;
+			}
+
+			let count = ExecutedCount::<T>::get();
+			let slot = (count % u64::from(max_length)) as u32;
+			let block = frame_system::Pallet::<T>::block_number();
+			MotionHistory::<T>::insert(
+				slot,
+				ExecutedMotion { motion_hash, level, block, result, proposal_index },
+			);
+			ExecutedCount::<T>::put(count.saturating_add(1));
+		}
+
+		/// Dispatch class of a queued motion, `Normal` if there is no motion queued under
+		/// `motion_hash`.
+		pub fn pending_motion_class(motion_hash: &T::Hash) -> DispatchClass {
+			PendingMotions::<T>::get(motion_hash)
+				.map_or(DispatchClass::Normal, |motion| motion.class)
+		}
+
+		/// Stores the reason a motion was cancelled until it expires, making room for it by
+		/// removing the oldest reason if needed.
+		fn record_cancellation(motion_hash: T::Hash, reason: BoundedVec<u8, T::MaxReasonLength>) {
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::CancellationRetention::get());
+			CancellationExpiries::<T>::mutate(|expiries| {
+				// A motion queued again after being cancelled keeps only its latest reason
+				expiries.retain(|(_, hash)| *hash != motion_hash);
+				if expiries.is_full() && !expiries.is_empty() {
+					let (_, oldest) = expiries.remove(0);
+					CancelledMotions::<T>::remove(oldest);
+				}
+				if expiries.try_push((expires_at, motion_hash)).is_ok() {
+					CancelledMotions::<T>::insert(motion_hash, reason);
+				}
+			});
+		}
+
+		/// Removes every entry of the motion history, to be called by the migration changing
+		/// `MaxHistoryLength`. Motions executed afterwards fill the slots of the new length.
+		pub fn clear_motion_history() -> Weight {
+			let removed = MotionHistory::<T>::clear(u32::MAX, None).unique;
+			T::DbWeight::get().writes(u64::from(removed))
+		}
+
+		/// Executed motions still in the motion history, oldest first.
+		pub fn executed_motions() -> Vec<ExecutedMotion<T::Hash, BlockNumberFor<T>>> {
+			let max_length = u64::from(T::MaxHistoryLength::get());
+			let count = ExecutedCount::<T>::get();
+			(count.saturating_sub(max_length)..count)
+				.filter_map(|n| MotionHistory::<T>::get((n % max_length) as u32))
+				.collect()
+		}
+
+		/// Hash a motion is identified by. It covers the majority level and the origin along with
+		/// the calls, so the same calls approved by different majorities are distinct motions.
+		pub fn motion_hash(
+			call: &MotionCall<T>,
+			level: MajorityLevel,
+			dispatch_as: DispatchAs,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(call, level, dispatch_as))
+		}
+
+		/// Returns true if a motion approved by `level` may dispatch `call`.
+		pub fn is_call_allowed(call: &<T as Config>::RuntimeCall, level: MajorityLevel) -> bool {
+			match level {
+				MajorityLevel::SimpleMajority => T::SimpleMajorityCalls::contains(call),
+				MajorityLevel::SuperMajority => T::SuperMajorityCalls::contains(call),
+				MajorityLevel::Unanimous => T::UnanimousCalls::contains(call),
+			}
+		}
+
+		/// Delay between the approval of a motion and its execution.
+		pub fn delay(level: MajorityLevel) -> BlockNumberFor<T> {
+			match level {
+				MajorityLevel::SimpleMajority => T::SimpleMajorityDelay::get(),
+				MajorityLevel::SuperMajority => T::SuperMajorityDelay::get(),
+				MajorityLevel::Unanimous => T::UnanimousDelay::get(),
+			}
+		}
+
+		/// Helper to actually dispatch the calls of a motion.
 		///
 		/// Should only be called after the origin is ensured.
 		///
-		/// Returns the `DispatchResult` from the dispatchable call.
-		fn do_dispatch(call: Box<<T as Config>::RuntimeCall>) -> DispatchResult {
-			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
-			let motion_result = res.map(|_| ()).map_err(|e| e.error);
-			motion_result
+		/// Returns the `DispatchResult` from the dispatchable call, or the first failure of a
+		/// batch, along with the actual weight of the dispatched calls.
+		fn do_dispatch(
+			call: MotionCall<T>,
+			level: MajorityLevel,
+			dispatch_as: DispatchAs,
+		) -> Result<(DispatchResult, Weight), DispatchError> {
+			let origin: OriginFor<T> = match dispatch_as {
+				DispatchAs::Root => frame_system::RawOrigin::Root.into(),
+				DispatchAs::MotionOrigin => T::MotionOrigin::convert(level),
+			};
+
+			let (calls, atomic) = match call {
+				MotionCall::Single(call) => return Ok(Self::dispatch_call(*call, origin)),
+				MotionCall::Batch { calls, atomic } => (calls, atomic),
+			};
+
+			let mut results = Vec::with_capacity(calls.len());
+			let mut weight = Weight::zero();
+			let dispatch_calls = || {
+				for call in calls {
+					let (result, call_weight) = Self::dispatch_call(call, origin.clone());
+					weight.saturating_accrue(call_weight);
+					let failed = result.is_err();
+					results.push(result);
+					if atomic && failed {
+						return true;
+					}
+				}
+				false
+			};
+			let rolled_back = if atomic {
+				with_transaction(|| {
+					if dispatch_calls() {
+						TransactionOutcome::Rollback(Ok::<_, DispatchError>(true))
+					} else {
+						TransactionOutcome::Commit(Ok(false))
+					}
+				})?
+			} else {
+				dispatch_calls()
+			};
+
+			let motion_result = results.iter().find(|result| result.is_err()).cloned();
+			Self::deposit_event(Event::BatchDispatched { level, results, rolled_back });
+			Ok((motion_result.unwrap_or(Ok(())), weight))
+		}
+
+		/// Dispatches a single call, returning its result and actual weight.
+		fn dispatch_call(
+			call: <T as Config>::RuntimeCall,
+			origin: OriginFor<T>,
+		) -> (DispatchResult, Weight) {
+			let info = call.get_dispatch_info();
+			let res = call.dispatch_bypass_filter(origin);
+			let weight = extract_actual_weight(&res, &info);
+			(res.map(|_| ()).map_err(|e| e.error), weight)
 		}
 	}
 }
+
+/// Converts a majority level into the custom [`Origin`] of this pallet.
+pub struct AsMotionOrigin<O>(PhantomData<O>);
+impl<O: From<RawOrigin>> Convert<MajorityLevel, O> for AsMotionOrigin<O> {
+	fn convert(level: MajorityLevel) -> O {
+		RawOrigin::Motion(level).into()
+	}
+}
+
+/// Ensures the origin is a motion approved by at least the majority `Level`.
+pub struct EnsureMotion<Level>(PhantomData<Level>);
+impl<O, Level> EnsureOrigin<O> for EnsureMotion<Level>
+where
+	O: Into<Result<RawOrigin, O>> + From<RawOrigin>,
+	Level: Get<MajorityLevel>,
+{
+	type Success = MajorityLevel;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Motion(level) if level >= Level::get() => Ok(level),
+			o => Err(O::from(o)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(RawOrigin::Motion(Level::get())))
+	}
+}
+
+/// Provides the index of the collective proposal with a given hash.
+pub trait ProposalIndexOf<Hash> {
+	/// Index of the proposal, if it is still open.
+	fn proposal_index(proposal_hash: &Hash) -> Option<ProposalIndex>;
+}
+
+impl<Hash> ProposalIndexOf<Hash> for () {
+	fn proposal_index(_proposal_hash: &Hash) -> Option<ProposalIndex> {
+		None
+	}
+}
+
+/// Reads the proposal index from the votes of a `pallet_collective` instance. A proposal is only
+/// removed after being executed, so it is still stored while it dispatches a motion.
+pub struct CollectiveProposalIndex<T, I>(PhantomData<(T, I)>);
+impl<T, I> ProposalIndexOf<T::Hash> for CollectiveProposalIndex<T, I>
+where
+	T: pallet_collective::Config<I>,
+	I: 'static,
+{
+	fn proposal_index(proposal_hash: &T::Hash) -> Option<ProposalIndex> {
+		pallet_collective::Pallet::<T, I>::voting(proposal_hash).map(|votes| votes.index)
+	}
+}
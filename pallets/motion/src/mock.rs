@@ -18,7 +18,7 @@ use super::*;
 pub(crate) use crate as pallet_motion;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64, Contains, EitherOfDiverse, Everything},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -95,6 +95,12 @@ parameter_types! {
 	pub MaxProposalWeight: Weight = sp_runtime::Perbill::from_percent(80) * BlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub static SimpleMajorityDelay: u64 = 0;
+	pub static SuperMajorityDelay: u64 = 0;
+	pub static UnanimousDelay: u64 = 0;
+}
+
 pub type CouncilCollective = pallet_collective::Instance1;
 impl pallet_collective::Config<CouncilCollective> for Test {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -105,10 +111,29 @@ impl pallet_collective::Config<CouncilCollective> for Test {
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type WeightInfo = ();
-	type SetMembersOrigin = EnsureRoot<u64>;
+	type SetMembersOrigin = EitherOfDiverse<
+		EnsureRoot<u64>,
+		pallet_motion::EnsureMotion<pallet_motion::types::SuperMajorityLevel>,
+	>;
 	type MaxProposalWeight = MaxProposalWeight;
 }
 
+/// System calls require at least a super majority.
+pub struct SimpleMajorityCalls;
+impl Contains<RuntimeCall> for SimpleMajorityCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::System(..))
+	}
+}
+
+/// System calls stand in for runtime upgrades, the only motions that can be fast-tracked.
+pub struct FastTrackCalls;
+impl Contains<RuntimeCall> for FastTrackCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(..))
+	}
+}
+
 impl pallet_motion::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -117,6 +142,22 @@ impl pallet_motion::Config for Test {
 	type SuperMajorityOrigin =
 		pallet_collective::EnsureProportionAtLeast<u64, CouncilCollective, 2, 3>;
 	type UnanimousOrigin = pallet_collective::EnsureProportionAtLeast<u64, CouncilCollective, 1, 1>;
+	type SimpleMajorityDelay = SimpleMajorityDelay;
+	type SuperMajorityDelay = SuperMajorityDelay;
+	type UnanimousDelay = UnanimousDelay;
+	type SimpleMajorityCalls = SimpleMajorityCalls;
+	type SuperMajorityCalls = Everything;
+	type UnanimousCalls = Everything;
+	type VetoOrigin = pallet_collective::EnsureProportionAtLeast<u64, CouncilCollective, 1, 1>;
+	type MaxReasonLength = ConstU32<32>;
+	type WeightInfo = ();
+	type MaxBatchCalls = ConstU32<4>;
+	type MotionOrigin = pallet_motion::AsMotionOrigin<RuntimeOrigin>;
+	type CollectiveProposals = pallet_motion::CollectiveProposalIndex<Test, CouncilCollective>;
+	type MaxHistoryLength = ConstU32<3>;
+	type FastTrackOrigin = EnsureRoot<u64>;
+	type FastTrackCalls = FastTrackCalls;
+	type CancellationRetention = ConstU64<5>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -17,13 +17,22 @@
 use super::*;
 use crate as pallet_motion;
 use crate::{mock::*, Event as MotionEvent};
-use frame_support::{assert_ok, dispatch::GetDispatchInfo, weights::Weight};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchClass, DispatchResultWithPostInfo, Dispatchable, GetDispatchInfo, Pays},
+	traits::{ConstU32, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::{EventRecord, Phase};
 use mock::{RuntimeCall, RuntimeEvent};
 use pallet_collective::Event as CollectiveEvent;
 use parity_scale_codec::Encode;
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
 
 fn record(event: RuntimeEvent) -> EventRecord<RuntimeEvent, H256> {
 	EventRecord { phase: Phase::Initialization, event, topics: vec![] }
@@ -538,3 +547,912 @@ fn unanimous_fails() {
 		);
 	});
 }
+
+fn council_origin(yes_votes: u32) -> RuntimeOrigin {
+	pallet_collective::RawOrigin::<u64, CouncilCollective>::Members(yes_votes, 5).into()
+}
+
+/// Hash of the motion dispatching `call` with `Root` origin once approved by `level`.
+fn root_motion_hash(call: &Box<RuntimeCall>, level: MajorityLevel) -> H256 {
+	Motion::motion_hash(&MotionCall::Single(call.clone()), level, DispatchAs::Root)
+}
+
+fn set_balance_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+		who: 5,
+		new_free: 5,
+	}))
+}
+
+#[test]
+fn delayed_motion_is_queued() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		let call = set_balance_call();
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+
+		assert_ok!(Motion::simple_majority(council_origin(3), call.clone()));
+
+		// The call is not dispatched yet
+		assert_eq!(Balances::free_balance(5), 50);
+		assert_eq!(
+			Motion::pending_motion(motion_hash),
+			Some(PendingMotion {
+				call: MotionCall::Single(call),
+				level: MajorityLevel::SimpleMajority,
+				dispatch_as: DispatchAs::Root,
+				ready_at: 11,
+				class: DispatchClass::Normal,
+				proposal_index: None,
+			})
+		);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionQueued {
+			motion_hash,
+			level: MajorityLevel::SimpleMajority,
+			ready_at: 11,
+		}));
+	});
+}
+
+#[test]
+fn delay_is_configured_per_majority_level() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		UnanimousDelay::set(0);
+
+		assert_ok!(Motion::unanimous(council_origin(5), set_balance_call()));
+
+		assert_eq!(Balances::free_balance(5), 5);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::DispatchUnanimous {
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn same_motion_can_not_be_queued_twice() {
+	new_test_ext().execute_with(|| {
+		SuperMajorityDelay::set(10);
+		assert_ok!(Motion::super_majority(council_origin(4), set_balance_call()));
+
+		assert_noop!(
+			Motion::super_majority(council_origin(4), set_balance_call()),
+			Error::<Test>::MotionAlreadyQueued
+		);
+	});
+}
+
+#[test]
+fn execute_ready_works() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		let call = set_balance_call();
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+		let weight = call.get_dispatch_info().weight;
+		assert_ok!(Motion::simple_majority(council_origin(3), call));
+
+		System::set_block_number(11);
+		assert_ok!(Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, weight));
+
+		assert_eq!(Balances::free_balance(5), 5);
+		assert_eq!(Motion::pending_motion(motion_hash), None);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionExecuted {
+			motion_hash,
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn queued_operational_motion_keeps_its_class() {
+	new_test_ext().execute_with(|| {
+		SuperMajorityDelay::set(10);
+		// Runtime upgrades are `Operational` and take a full block
+		let call = Box::new(RuntimeCall::System(frame_system::Call::set_code_without_checks {
+			code: vec![1, 2, 3],
+		}));
+		let call_info = call.get_dispatch_info();
+		assert_eq!(call_info.class, DispatchClass::Operational);
+		assert_eq!(call_info.weight, BlockWeights::get().max_block);
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SuperMajority);
+		assert_ok!(Motion::super_majority(council_origin(4), call));
+		assert_eq!(
+			Motion::pending_motion(motion_hash).map(|motion| motion.class),
+			Some(DispatchClass::Operational)
+		);
+
+		System::set_block_number(11);
+		let execute_ready = RuntimeCall::Motion(pallet_motion::Call::execute_ready {
+			motion_hash,
+			weight_bound: call_info.weight,
+		});
+		let info = execute_ready.get_dispatch_info();
+		assert_eq!(info.class, DispatchClass::Operational);
+		assert!(info.weight.all_gte(call_info.weight));
+
+		assert_ok!(execute_ready.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(
+			sp_io::storage::get(sp_core::storage::well_known_keys::CODE),
+			Some(vec![1, 2, 3].into())
+		);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionExecuted {
+			motion_hash,
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn execute_ready_fails_before_delay() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		let call = set_balance_call();
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+		let weight = call.get_dispatch_info().weight;
+		assert_ok!(Motion::simple_majority(council_origin(3), call));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, weight),
+			Error::<Test>::MotionNotReady
+		);
+	});
+}
+
+#[test]
+fn execute_ready_fails_with_wrong_weight_bound() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		let call = set_balance_call();
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+		assert_ok!(Motion::simple_majority(council_origin(3), call));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, Weight::zero()),
+			Error::<Test>::WrongWeightBound
+		);
+	});
+}
+
+#[test]
+fn execute_ready_fails_for_unknown_motion() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::execute_ready(RuntimeOrigin::signed(1), H256::zero(), Weight::zero()),
+			Error::<Test>::MotionNotFound
+		);
+	});
+}
+
+fn queue_simple_majority_motion() -> H256 {
+	SimpleMajorityDelay::set(10);
+	let call = set_balance_call();
+	let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+	assert_ok!(Motion::simple_majority(council_origin(3), call));
+	motion_hash
+}
+
+#[test]
+fn cancel_works() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_simple_majority_motion();
+		let reason: BoundedVec<u8, ConstU32<32>> =
+			b"compromised majority".to_vec().try_into().unwrap();
+
+		assert_ok!(Motion::cancel(council_origin(5), motion_hash, reason.clone()));
+
+		assert_eq!(Motion::pending_motion(motion_hash), None);
+		assert_eq!(Motion::cancellation_reason(motion_hash), Some(reason.clone()));
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionCancelled {
+			motion_hash,
+			reason,
+		}));
+
+		// The cancelled motion can not be executed anymore
+		System::set_block_number(11);
+		assert_noop!(
+			Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, Weight::MAX),
+			Error::<Test>::MotionNotFound
+		);
+		assert_eq!(Balances::free_balance(5), 50);
+	});
+}
+
+#[test]
+fn cancel_requires_veto_origin() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_simple_majority_motion();
+
+		assert_noop!(
+			Motion::cancel(council_origin(4), motion_hash, Default::default()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Motion::cancel(RuntimeOrigin::signed(1), motion_hash, Default::default()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn cancel_fails_for_unknown_motion() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::cancel(council_origin(5), H256::zero(), Default::default()),
+			Error::<Test>::MotionNotFound
+		);
+	});
+}
+
+#[test]
+fn same_call_is_a_distinct_motion_per_majority_level() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		SuperMajorityDelay::set(10);
+		let call = set_balance_call();
+		let simple_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+		let super_hash = root_motion_hash(&call, MajorityLevel::SuperMajority);
+		assert_ne!(simple_hash, super_hash);
+
+		assert_ok!(Motion::simple_majority(council_origin(3), call.clone()));
+		assert_ok!(Motion::super_majority(council_origin(4), call));
+
+		// Vetoing the simple majority motion leaves the super majority one queued
+		assert_ok!(Motion::cancel(council_origin(5), simple_hash, Default::default()));
+		assert_eq!(Motion::pending_motion(simple_hash), None);
+		assert_eq!(
+			Motion::pending_motion(super_hash).map(|motion| motion.level),
+			Some(MajorityLevel::SuperMajority)
+		);
+	});
+}
+
+#[test]
+fn cancellation_reason_expires_after_retention() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_simple_majority_motion();
+		let reason: BoundedVec<u8, ConstU32<32>> = b"expired".to_vec().try_into().unwrap();
+		assert_ok!(Motion::cancel(council_origin(5), motion_hash, reason.clone()));
+		assert_eq!(Motion::cancellation_expiries().into_inner(), vec![(6, motion_hash)]);
+
+		Motion::on_initialize(5);
+		assert_eq!(Motion::cancellation_reason(motion_hash), Some(reason));
+
+		Motion::on_initialize(6);
+		assert_eq!(Motion::cancellation_reason(motion_hash), None);
+		assert!(Motion::cancellation_expiries().is_empty());
+	});
+}
+
+#[test]
+fn oldest_cancellation_reason_is_evicted_when_full() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		// `MaxHistoryLength` is 3 in the mock
+		let hashes: Vec<H256> = (1..=4)
+			.map(|n| {
+				let call = Box::new(balance_call(5, n));
+				let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+				assert_ok!(Motion::simple_majority(council_origin(3), call));
+				assert_ok!(Motion::cancel(council_origin(5), motion_hash, Default::default()));
+				motion_hash
+			})
+			.collect();
+
+		assert_eq!(Motion::cancellation_reason(hashes[0]), None);
+		for motion_hash in &hashes[1..] {
+			assert_eq!(Motion::cancellation_reason(motion_hash), Some(Default::default()));
+		}
+		assert_eq!(Motion::cancellation_expiries().len(), 3);
+	});
+}
+
+fn queue_remark_motion() -> H256 {
+	SuperMajorityDelay::set(10);
+	let call = remark_call();
+	let motion_hash = root_motion_hash(&call, MajorityLevel::SuperMajority);
+	assert_ok!(Motion::super_majority(council_origin(4), call));
+	motion_hash
+}
+
+#[test]
+fn fast_track_works() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_remark_motion();
+		let weight = remark_call().get_dispatch_info().weight;
+
+		System::set_block_number(2);
+		assert_ok!(Motion::fast_track(RuntimeOrigin::root(), motion_hash));
+
+		assert_eq!(Motion::pending_motion(motion_hash).map(|motion| motion.ready_at), Some(2));
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionFastTracked {
+			motion_hash,
+			ready_at: 2,
+		}));
+
+		// The motion is executable right away
+		assert_ok!(Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, weight));
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionExecuted {
+			motion_hash,
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn fast_tracked_motion_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_remark_motion();
+		assert_ok!(Motion::fast_track(RuntimeOrigin::root(), motion_hash));
+
+		assert_ok!(Motion::cancel(council_origin(5), motion_hash, Default::default()));
+		assert_eq!(Motion::pending_motion(motion_hash), None);
+	});
+}
+
+#[test]
+fn fast_track_requires_fast_track_origin() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_remark_motion();
+
+		assert_noop!(
+			Motion::fast_track(council_origin(5), motion_hash),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Motion::fast_track(RuntimeOrigin::signed(1), motion_hash),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fast_track_fails_for_calls_not_allowed() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_simple_majority_motion();
+
+		assert_noop!(
+			Motion::fast_track(RuntimeOrigin::root(), motion_hash),
+			Error::<Test>::CannotFastTrack
+		);
+		assert_eq!(Motion::pending_motion(motion_hash).map(|motion| motion.ready_at), Some(11));
+	});
+}
+
+#[test]
+fn fast_track_fails_for_unknown_motion() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::fast_track(RuntimeOrigin::root(), H256::zero()),
+			Error::<Test>::MotionNotFound
+		);
+	});
+}
+
+fn remark_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![1, 2, 3] }))
+}
+
+#[test]
+fn motion_refuses_calls_not_allowed_for_its_majority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::simple_majority(council_origin(3), remark_call()),
+			Error::<Test>::CallNotAllowed
+		);
+
+		// Calls are checked before being queued
+		SimpleMajorityDelay::set(10);
+		assert_noop!(
+			Motion::simple_majority(council_origin(3), remark_call()),
+			Error::<Test>::CallNotAllowed
+		);
+		assert_eq!(
+			Motion::pending_motion(root_motion_hash(&remark_call(), MajorityLevel::SimpleMajority)),
+			None
+		);
+	});
+}
+
+#[test]
+fn higher_majority_can_dispatch_restricted_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Motion::super_majority(council_origin(4), remark_call()));
+
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::DispatchSuperMajority {
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+fn set_members_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Council(pallet_collective::Call::set_members {
+		new_members: vec![1, 2, 3],
+		prime: None,
+		old_count: 5,
+	}))
+}
+
+#[test]
+fn motion_origin_is_accepted_by_its_majority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Motion::dispatch_as_motion_origin(
+			council_origin(4),
+			MajorityLevel::SuperMajority,
+			set_members_call()
+		));
+
+		assert_eq!(Council::members(), vec![1, 2, 3]);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::DispatchMotionOrigin {
+			level: MajorityLevel::SuperMajority,
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn motion_origin_is_not_root() {
+	new_test_ext().execute_with(|| {
+		// `set_members` requires at least a super majority motion origin
+		assert_ok!(Motion::dispatch_as_motion_origin(
+			council_origin(3),
+			MajorityLevel::SimpleMajority,
+			set_members_call()
+		));
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::DispatchMotionOrigin {
+			level: MajorityLevel::SimpleMajority,
+			motion_result: Err(DispatchError::BadOrigin),
+		}));
+
+		// `force_set_balance` requires Root
+		assert_ok!(Motion::dispatch_as_motion_origin(
+			council_origin(5),
+			MajorityLevel::Unanimous,
+			set_balance_call()
+		));
+		assert_eq!(Balances::free_balance(5), 50);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::DispatchMotionOrigin {
+			level: MajorityLevel::Unanimous,
+			motion_result: Err(DispatchError::BadOrigin),
+		}));
+	});
+}
+
+#[test]
+fn dispatch_as_motion_origin_requires_majority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::dispatch_as_motion_origin(
+				council_origin(3),
+				MajorityLevel::SuperMajority,
+				set_members_call()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Motion::dispatch_as_motion_origin(
+				council_origin(3),
+				MajorityLevel::SimpleMajority,
+				remark_call()
+			),
+			Error::<Test>::CallNotAllowed
+		);
+	});
+}
+
+#[test]
+fn queued_motion_keeps_its_origin() {
+	new_test_ext().execute_with(|| {
+		SuperMajorityDelay::set(10);
+		let call = set_members_call();
+		let motion_hash = Motion::motion_hash(
+			&MotionCall::Single(call.clone()),
+			MajorityLevel::SuperMajority,
+			DispatchAs::MotionOrigin,
+		);
+		let weight = call.get_dispatch_info().weight;
+		assert_ok!(Motion::dispatch_as_motion_origin(
+			council_origin(4),
+			MajorityLevel::SuperMajority,
+			call
+		));
+		assert_eq!(
+			Motion::pending_motion(motion_hash).map(|motion| motion.dispatch_as),
+			Some(DispatchAs::MotionOrigin)
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, weight));
+
+		assert_eq!(Council::members(), vec![1, 2, 3]);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::MotionExecuted {
+			motion_hash,
+			motion_result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn ensure_motion_checks_majority_level() {
+	type EnsureSuperMajority = EnsureMotion<types::SuperMajorityLevel>;
+	let motion_origin = |level| -> RuntimeOrigin { RawOrigin::Motion(level).into() };
+
+	assert_eq!(
+		EnsureSuperMajority::try_origin(motion_origin(MajorityLevel::Unanimous)).ok(),
+		Some(MajorityLevel::Unanimous)
+	);
+	assert_eq!(
+		EnsureSuperMajority::try_origin(motion_origin(MajorityLevel::SuperMajority)).ok(),
+		Some(MajorityLevel::SuperMajority)
+	);
+	assert!(EnsureSuperMajority::try_origin(motion_origin(MajorityLevel::SimpleMajority)).is_err());
+	assert!(EnsureSuperMajority::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+fn balance_call(who: u64, new_free: u64) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who, new_free })
+}
+
+// Fails with `BadOrigin` when dispatched by a motion
+fn failing_call() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 1, value: 1 })
+}
+
+fn batch(calls: Vec<RuntimeCall>, atomic: bool) -> DispatchResultWithPostInfo {
+	Motion::batch(council_origin(4), MajorityLevel::SuperMajority, DispatchAs::Root, calls, atomic)
+}
+
+#[test]
+fn batch_dispatches_every_call() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(batch(vec![balance_call(5, 5), failing_call(), balance_call(4, 4)], false));
+
+		assert_eq!(Balances::free_balance(5), 5);
+		assert_eq!(Balances::free_balance(4), 4);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::BatchDispatched {
+			level: MajorityLevel::SuperMajority,
+			results: vec![Ok(()), Err(DispatchError::BadOrigin), Ok(())],
+			rolled_back: false,
+		}));
+	});
+}
+
+#[test]
+fn atomic_batch_rolls_back_on_first_failure() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(batch(vec![balance_call(5, 5), failing_call(), balance_call(4, 4)], true));
+
+		assert_eq!(Balances::free_balance(5), 50);
+		assert_eq!(Balances::free_balance(4), 40);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::BatchDispatched {
+			level: MajorityLevel::SuperMajority,
+			results: vec![Ok(()), Err(DispatchError::BadOrigin)],
+			rolled_back: true,
+		}));
+	});
+}
+
+#[test]
+fn atomic_batch_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(batch(vec![balance_call(5, 5), balance_call(4, 4)], true));
+
+		assert_eq!(Balances::free_balance(5), 5);
+		assert_eq!(Balances::free_balance(4), 4);
+		System::assert_last_event(RuntimeEvent::Motion(MotionEvent::BatchDispatched {
+			level: MajorityLevel::SuperMajority,
+			results: vec![Ok(()), Ok(())],
+			rolled_back: false,
+		}));
+	});
+}
+
+#[test]
+fn batch_reports_weight_of_dispatched_calls() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![balance_call(5, 5), failing_call(), balance_call(4, 4)];
+		let overhead = <() as WeightInfo>::batch(3);
+		let declared = calls
+			.iter()
+			.fold(overhead, |total, call| total + call.get_dispatch_info().weight);
+		let dispatched = overhead
+			+ balance_call(5, 5).get_dispatch_info().weight
+			+ failing_call().get_dispatch_info().weight;
+
+		let info = RuntimeCall::Motion(pallet_motion::Call::batch {
+			level: MajorityLevel::SuperMajority,
+			dispatch_as: DispatchAs::Root,
+			calls: calls.clone(),
+			atomic: true,
+		})
+		.get_dispatch_info();
+		assert_eq!(info.weight, declared);
+
+		let post_info = batch(calls, true).unwrap();
+		assert_eq!(post_info.actual_weight, Some(dispatched));
+		assert_eq!(post_info.pays_fee, Pays::No);
+	});
+}
+
+#[test]
+fn batch_is_operational_only_if_every_call_is() {
+	new_test_ext().execute_with(|| {
+		let set_code = RuntimeCall::System(frame_system::Call::set_code_without_checks {
+			code: vec![1, 2, 3],
+		});
+		let batch_class = |calls: Vec<RuntimeCall>| {
+			RuntimeCall::Motion(pallet_motion::Call::batch {
+				level: MajorityLevel::SuperMajority,
+				dispatch_as: DispatchAs::Root,
+				calls,
+				atomic: true,
+			})
+			.get_dispatch_info()
+			.class
+		};
+
+		assert_eq!(
+			batch_class(vec![set_code.clone(), set_code.clone()]),
+			DispatchClass::Operational
+		);
+		assert_eq!(batch_class(vec![set_code, balance_call(5, 5)]), DispatchClass::Normal);
+		assert_eq!(batch_class(vec![]), DispatchClass::Normal);
+	});
+}
+
+#[test]
+fn batch_checks_every_call() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Motion::batch(
+				council_origin(3),
+				MajorityLevel::SimpleMajority,
+				DispatchAs::Root,
+				vec![balance_call(5, 5), *remark_call()],
+				false
+			),
+			Error::<Test>::CallNotAllowed
+		);
+		assert_noop!(batch(vec![balance_call(5, 5); 5], false), Error::<Test>::TooManyCalls);
+		assert_noop!(
+			Motion::batch(
+				council_origin(3),
+				MajorityLevel::SuperMajority,
+				DispatchAs::Root,
+				vec![balance_call(5, 5)],
+				false
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn queued_batch_works() {
+	new_test_ext().execute_with(|| {
+		SuperMajorityDelay::set(10);
+		let calls = vec![balance_call(5, 5), failing_call()];
+		let motion_call = MotionCall::<Test>::Batch { calls: calls.clone(), atomic: false };
+		let motion_hash =
+			Motion::motion_hash(&motion_call, MajorityLevel::SuperMajority, DispatchAs::Root);
+		let weight = motion_call.weight();
+
+		assert_ok!(batch(calls, false));
+		assert_eq!(Balances::free_balance(5), 50);
+		assert_eq!(
+			Motion::pending_motion(motion_hash).map(|motion| motion.call),
+			Some(motion_call)
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, weight));
+
+		assert_eq!(Balances::free_balance(5), 5);
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.rev()
+				.take(2)
+				.map(|record| record.event)
+				.collect::<Vec<_>>(),
+			vec![
+				RuntimeEvent::Motion(MotionEvent::MotionExecuted {
+					motion_hash,
+					motion_result: Err(DispatchError::BadOrigin),
+				}),
+				RuntimeEvent::Motion(MotionEvent::BatchDispatched {
+					level: MajorityLevel::SuperMajority,
+					results: vec![Ok(()), Err(DispatchError::BadOrigin)],
+					rolled_back: false,
+				}),
+			]
+		);
+	});
+}
+
+#[test]
+fn motion_reports_overhead_and_actual_weight() {
+	new_test_ext().execute_with(|| {
+		let call = set_balance_call();
+		let call_weight = call.get_dispatch_info().weight;
+		let overhead = <() as WeightInfo>::simple_majority();
+
+		let info = RuntimeCall::Motion(pallet_motion::Call::simple_majority { call: call.clone() })
+			.get_dispatch_info();
+		assert_eq!(info.weight, overhead + call_weight);
+
+		let post_info = Motion::simple_majority(council_origin(3), call.clone()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(overhead + call_weight));
+		assert_eq!(post_info.pays_fee, Pays::No);
+
+		// Queued calls are only charged when executed
+		SimpleMajorityDelay::set(10);
+		let post_info = Motion::simple_majority(council_origin(3), call.clone()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(overhead));
+
+		System::set_block_number(11);
+		let motion_hash = root_motion_hash(&call, MajorityLevel::SimpleMajority);
+		let post_info =
+			Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, Weight::MAX).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::execute_ready() + call_weight)
+		);
+	});
+}
+
+fn approve_proposal(proposal: Proposal) {
+	assert_ok!(Council::vote(RuntimeOrigin::signed(1), proposal.hash, 0, true));
+	assert_ok!(Council::vote(RuntimeOrigin::signed(2), proposal.hash, 0, true));
+	assert_ok!(Council::vote(RuntimeOrigin::signed(3), proposal.hash, 0, true));
+	assert_ok!(Council::close(
+		RuntimeOrigin::signed(4),
+		proposal.hash,
+		0,
+		proposal.weight,
+		proposal.len
+	));
+}
+
+#[test]
+fn history_records_executed_motions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		approve_proposal(setup_proposal(3, MotionType::SimpleMajority));
+
+		assert_eq!(Motion::executed_count(), 1);
+		assert_eq!(
+			Motion::executed_motions(),
+			vec![ExecutedMotion {
+				motion_hash: root_motion_hash(&set_balance_call(), MajorityLevel::SimpleMajority),
+				level: MajorityLevel::SimpleMajority,
+				block: 3,
+				result: Ok(()),
+				proposal_index: Some(0),
+			}]
+		);
+	});
+}
+
+#[test]
+fn history_records_queued_motions_once_executed() {
+	new_test_ext().execute_with(|| {
+		SimpleMajorityDelay::set(10);
+		approve_proposal(setup_proposal(3, MotionType::SimpleMajority));
+		let motion_hash = root_motion_hash(&set_balance_call(), MajorityLevel::SimpleMajority);
+		assert_eq!(Motion::pending_motion(motion_hash).unwrap().proposal_index, Some(0));
+		assert_eq!(Motion::executed_motions(), vec![]);
+
+		System::set_block_number(11);
+		assert_ok!(Motion::execute_ready(RuntimeOrigin::signed(1), motion_hash, Weight::MAX));
+
+		assert_eq!(
+			Motion::executed_motions(),
+			vec![ExecutedMotion {
+				motion_hash,
+				level: MajorityLevel::SimpleMajority,
+				block: 11,
+				result: Ok(()),
+				proposal_index: Some(0),
+			}]
+		);
+	});
+}
+
+#[test]
+fn history_records_the_index_of_the_approving_proposal() {
+	new_test_ext().execute_with(|| {
+		// An unrelated proposal takes index 0
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+		assert_ok!(Council::propose(
+			RuntimeOrigin::signed(1),
+			3,
+			Box::new(remark.clone()),
+			remark.encoded_size() as u32
+		));
+		let proposal = setup_proposal(3, MotionType::SimpleMajority);
+
+		for member in 1..=3 {
+			assert_ok!(Council::vote(RuntimeOrigin::signed(member), proposal.hash, 1, true));
+		}
+		assert_ok!(Council::close(
+			RuntimeOrigin::signed(4),
+			proposal.hash,
+			1,
+			proposal.weight,
+			proposal.len
+		));
+
+		assert_eq!(
+			Motion::executed_motions()
+				.into_iter()
+				.map(|motion| motion.proposal_index)
+				.collect::<Vec<_>>(),
+			vec![Some(1)]
+		);
+	});
+}
+
+#[test]
+fn clearing_the_history_keeps_the_executed_count() {
+	new_test_ext().execute_with(|| {
+		for n in 1..3 {
+			assert_ok!(Motion::simple_majority(council_origin(3), Box::new(balance_call(5, n))));
+		}
+		assert_eq!(Motion::executed_motions().len(), 2);
+
+		Motion::clear_motion_history();
+
+		assert_eq!(Motion::executed_motions(), vec![]);
+		assert_eq!(Motion::executed_count(), 2);
+		assert_ok!(Motion::simple_majority(council_origin(3), Box::new(balance_call(5, 7))));
+		assert_eq!(
+			Motion::executed_motions()
+				.into_iter()
+				.map(|motion| motion.motion_hash)
+				.collect::<Vec<_>>(),
+			vec![root_motion_hash(&Box::new(balance_call(5, 7)), MajorityLevel::SimpleMajority)]
+		);
+	});
+}
+
+#[test]
+fn history_keeps_the_last_motions() {
+	new_test_ext().execute_with(|| {
+		for new_free in 1..=4 {
+			let call = Box::new(balance_call(5, new_free));
+			assert_ok!(Motion::super_majority(council_origin(4), call));
+		}
+		assert_ok!(batch(vec![failing_call()], false));
+
+		// `MaxHistoryLength` is 3
+		assert_eq!(Motion::executed_count(), 5);
+		let history = Motion::executed_motions();
+		assert_eq!(
+			history.iter().map(|motion| motion.motion_hash).collect::<Vec<_>>(),
+			vec![
+				root_motion_hash(&Box::new(balance_call(5, 3)), MajorityLevel::SuperMajority),
+				root_motion_hash(&Box::new(balance_call(5, 4)), MajorityLevel::SuperMajority),
+				Motion::motion_hash(
+					&MotionCall::Batch { calls: vec![failing_call()], atomic: false },
+					MajorityLevel::SuperMajority,
+					DispatchAs::Root
+				),
+			]
+		);
+		assert_eq!(history[2].result, Err(DispatchError::BadOrigin));
+		assert!(history.iter().all(|motion| motion.proposal_index.is_none()));
+	});
+}
+
+#[test]
+fn history_ignores_cancelled_motions() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = queue_simple_majority_motion();
+		assert_ok!(Motion::cancel(council_origin(5), motion_hash, Default::default()));
+
+		assert_eq!(Motion::executed_count(), 0);
+		assert_eq!(Motion::executed_motions(), vec![]);
+	});
+}
@@ -0,0 +1,147 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Config, ProposalIndex};
+use frame_support::{
+	dispatch::{DispatchClass, GetDispatchInfo},
+	pallet_prelude::*,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{boxed::Box, vec::Vec};
+
+/// Council majority that approved a motion, ordered from the lowest to the highest.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum MajorityLevel {
+	SimpleMajority,
+	SuperMajority,
+	Unanimous,
+}
+
+frame_support::parameter_types! {
+	pub const SimpleMajorityLevel: MajorityLevel = MajorityLevel::SimpleMajority;
+	pub const SuperMajorityLevel: MajorityLevel = MajorityLevel::SuperMajority;
+	pub const UnanimousLevel: MajorityLevel = MajorityLevel::Unanimous;
+}
+
+/// Origin a motion call is dispatched with.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DispatchAs {
+	/// `Root` origin.
+	Root,
+	/// Origin configured for the majority level with `Config::MotionOrigin`.
+	MotionOrigin,
+}
+
+/// Custom origin of the calls dispatched by a motion.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RawOrigin {
+	/// The call was approved by a motion of the given majority.
+	Motion(MajorityLevel),
+}
+
+/// Calls dispatched by a motion.
+#[derive(
+	CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub enum MotionCall<T: Config> {
+	/// A single call.
+	Single(Box<<T as Config>::RuntimeCall>),
+	/// Calls dispatched in order. If `atomic`, the first failing call reverts the whole batch.
+	Batch { calls: Vec<<T as Config>::RuntimeCall>, atomic: bool },
+}
+
+impl<T: Config> MotionCall<T> {
+	/// Total weight of the calls.
+	pub fn weight(&self) -> Weight {
+		match self {
+			MotionCall::Single(call) => call.get_dispatch_info().weight,
+			MotionCall::Batch { calls, .. } => calls.iter().fold(Weight::zero(), |total, call| {
+				total.saturating_add(call.get_dispatch_info().weight)
+			}),
+		}
+	}
+
+	/// Dispatch class of the calls, see [`MotionCall::batch_class`] for batches.
+	pub fn class(&self) -> DispatchClass {
+		match self {
+			MotionCall::Single(call) => call.get_dispatch_info().class,
+			MotionCall::Batch { calls, .. } => Self::batch_class(calls),
+		}
+	}
+
+	/// Dispatch class of a batch: `Operational` if all of its calls are, `Normal` otherwise.
+	pub fn batch_class(calls: &[<T as Config>::RuntimeCall]) -> DispatchClass {
+		let all_operational = !calls.is_empty()
+			&& calls
+				.iter()
+				.all(|call| call.get_dispatch_info().class == DispatchClass::Operational);
+		if all_operational {
+			DispatchClass::Operational
+		} else {
+			DispatchClass::Normal
+		}
+	}
+}
+
+/// A motion approved by the council, waiting for its delay to pass before being executed.
+#[derive(
+	CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PendingMotion<T: Config> {
+	/// The calls dispatched once the motion is executed.
+	pub call: MotionCall<T>,
+	/// Majority that approved the motion.
+	pub level: MajorityLevel,
+	/// Origin the call is dispatched with.
+	pub dispatch_as: DispatchAs,
+	/// First block at which the motion can be executed.
+	pub ready_at: BlockNumberFor<T>,
+	/// Dispatch class of the calls, recorded when the motion is queued so `execute_ready` is
+	/// dispatched with the same class.
+	pub class: DispatchClass,
+	/// Index of the collective proposal that approved the motion.
+	pub proposal_index: Option<ProposalIndex>,
+}
+
+/// An executed motion, as recorded in the motion history.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExecutedMotion<Hash, BlockNumber> {
+	/// Hash of the motion, see `Pallet::motion_hash`.
+	pub motion_hash: Hash,
+	/// Majority that approved the motion.
+	pub level: MajorityLevel,
+	/// Block at which the motion was executed.
+	pub block: BlockNumber,
+	/// Result of the motion call, or the first failure of a batch.
+	pub result: DispatchResult,
+	/// Index of the collective proposal that approved the motion, if known.
+	pub proposal_index: Option<ProposalIndex>,
+}
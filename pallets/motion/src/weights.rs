@@ -0,0 +1,288 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_motion
+//!
+//! PLACEHOLDER VALUES, NOT MEASURED. These are conservative estimates and must be replaced by
+//! running the `pallet_motion` benchmarks.
+//!
+//! They only cover the overhead of the motion itself. The weight of the dispatched calls is
+//! added by the pallet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_motion.
+pub trait WeightInfo {
+	fn simple_majority() -> Weight;
+	fn super_majority() -> Weight;
+	fn unanimous() -> Weight;
+	fn execute_ready() -> Weight;
+	fn cancel() -> Weight;
+	fn dispatch_as_motion_origin() -> Weight;
+	fn batch(c: u32, ) -> Weight;
+	fn fast_track() -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
+}
+
+/// Weights for pallet_motion using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn simple_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn super_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn unanimous() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn execute_ready() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:2)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	fn cancel() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_500))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn dispatch_as_motion_origin() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[0, 32]`.
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn batch(c: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 100).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	fn fast_track() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:1)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 256]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9_713))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn simple_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn super_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn unanimous() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn execute_ready() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:2)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	fn cancel() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_500))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn dispatch_as_motion_origin() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[0, 32]`.
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn batch(c: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 100).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	fn fast_track() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:1)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 256]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9_713))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+}
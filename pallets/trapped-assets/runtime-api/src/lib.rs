@@ -0,0 +1,35 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API to query the assets trapped by XCM, as recorded by `pallet_trapped_assets`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_trapped_assets::types::AssetTrap;
+use sp_core::H256;
+use sp_std::vec::Vec;
+use xcm::VersionedMultiLocation;
+
+sp_api::decl_runtime_apis! {
+	pub trait TrappedAssetsApi {
+		/// Assets trapped by messages from `origin` and not claimed yet, with the hash they are
+		/// trapped under.
+		///
+		/// Assets trapped before the pallet was added are only included once governance recorded
+		/// them.
+		fn trapped_assets(origin: VersionedMultiLocation) -> Vec<(H256, AssetTrap)>;
+	}
+}
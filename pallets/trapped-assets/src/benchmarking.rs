@@ -0,0 +1,61 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks of the trapped assets pallet, including the execution of the recovery message.
+
+use crate::*;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use xcm::latest::Junction::{AccountId32, Parachain};
+
+use super::Pallet as TrappedAssets;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	recover_assets {
+		let origin = T::RecoveryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let trap_origin = MultiLocation::new(1, X1(Parachain(1000)));
+		let asset = T::BenchmarkHelper::trapped_asset();
+		let context = XcmContext { origin: Some(trap_origin), message_id: [0; 32], topic: None };
+		TrappedAssets::<T>::drop_assets(&trap_origin, asset.clone().into(), &context);
+		let assets = MultiAssets::from(asset);
+		let beneficiary: MultiLocation = AccountId32 { network: None, id: [1; 32] }.into();
+	}: _<T::RuntimeOrigin>(
+		origin,
+		Box::new(trap_origin.into()),
+		Box::new(assets.clone().into()),
+		Box::new(beneficiary.into()),
+		Weight::MAX
+	)
+	verify {
+		assert_eq!(Traps::<T>::iter_prefix(trap_origin).count(), 0);
+		assert_last_event::<T>(
+			Event::AssetsRecovered { origin: trap_origin, assets, beneficiary }.into()
+		);
+	}
+
+	record_trap {
+		let origin = T::RecoveryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let trap_origin = MultiLocation::new(1, X1(Parachain(1000)));
+		let assets = VersionedMultiAssets::from(MultiAssets::from(T::BenchmarkHelper::trapped_asset()));
+	}: _<T::RuntimeOrigin>(origin, Box::new(trap_origin.into()), Box::new(assets.clone()), 2)
+	verify {
+		let hash = TrappedAssets::<T>::trap_hash(&trap_origin, &assets);
+		assert_eq!(Traps::<T>::get(trap_origin, hash), Some(AssetTrap { assets, count: 2 }));
+	}
+}
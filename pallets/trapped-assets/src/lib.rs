@@ -0,0 +1,294 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Trapped Assets Pallet
+//!
+//! Keeps track of the assets trapped by the XCM executor by the origin of the message trapping
+//! them, so that they can be found and claimed.
+//!
+//! The pallet is the `AssetTrap` and `AssetClaims` of the XCM executor. It records the trapped
+//! assets before passing them on to the actual asset trap, usually `pallet_xcm`, and forgets them
+//! once they are claimed. Governance can recover trapped assets to a beneficiary on behalf of the
+//! origin that trapped them.
+//!
+//! Only the assets trapped once the pallet is the `AssetTrap` are recorded. Governance records the
+//! assets trapped before, found in the `AssetsTrapped` events of `pallet_xcm`, with `record_trap`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash, Saturating};
+use sp_std::{boxed::Box, prelude::*, vec};
+use xcm::{
+	latest::{
+		ExecuteXcm,
+		Instruction::{ClaimAsset, DepositAsset},
+		Junction::GeneralIndex,
+		Junctions::{Here, X1},
+		MultiAssetFilter::Wild,
+		MultiAssets, MultiLocation,
+		WildMultiAsset::AllCounted,
+		Xcm, XcmContext,
+	},
+	IntoVersion, VersionedMultiAssets, VersionedMultiLocation,
+};
+use xcm_executor::{
+	traits::{ClaimAssets, DropAssets},
+	Assets,
+};
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The asset trap the trapped assets are passed on to.
+		type AssetTrap: DropAssets;
+
+		/// Claims the assets trapped in `AssetTrap`.
+		type AssetClaims: ClaimAssets;
+
+		/// Executes the messages recovering trapped assets.
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Origin allowed to recover trapped assets on behalf of the origin that trapped them, and
+		/// to record traps.
+		type RecoveryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to set up the recovery benchmark.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Assets trapped by the messages from each origin and not claimed yet, by the hash they are
+	/// trapped under in `AssetTrap`.
+	#[pallet::storage]
+	pub type Traps<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MultiLocation, Identity, H256, AssetTrap>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The assets trapped by messages from `origin` under `hash` were recorded, and can be
+		/// claimed `count` times.
+		TrapRecorded { origin: MultiLocation, hash: H256, count: u32 },
+		/// The assets trapped by messages from `origin` under `hash` were all claimed, or the
+		/// record was removed.
+		TrapCleared { origin: MultiLocation, hash: H256 },
+		/// `assets` trapped by messages from `origin` were recovered to `beneficiary`.
+		AssetsRecovered { origin: MultiLocation, assets: MultiAssets, beneficiary: MultiLocation },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A location or the assets can not be converted to the current XCM version
+		BadVersion,
+		/// The assets are not trapped by the origin, or can not be deposited to the beneficiary
+		RecoveryFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Recovers `assets` trapped by messages from `trap_origin`, depositing them to
+		/// `beneficiary`.
+		///
+		/// The assets are claimed and deposited by a message executed as `trap_origin`, using at
+		/// most `max_weight`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::recover_assets().saturating_add(*max_weight))]
+		pub fn recover_assets(
+			origin: OriginFor<T>,
+			trap_origin: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			beneficiary: Box<VersionedMultiLocation>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			T::RecoveryOrigin::ensure_origin(origin)?;
+			let trap_origin =
+				MultiLocation::try_from(*trap_origin).map_err(|()| Error::<T>::BadVersion)?;
+			let assets = MultiAssets::try_from(*assets).map_err(|()| Error::<T>::BadVersion)?;
+			let beneficiary =
+				MultiLocation::try_from(*beneficiary).map_err(|()| Error::<T>::BadVersion)?;
+
+			let message = Xcm(vec![
+				ClaimAsset { assets: assets.clone(), ticket: Here.into() },
+				DepositAsset { assets: Wild(AllCounted(assets.len() as u32)), beneficiary },
+			]);
+			let hash = BlakeTwo256::hash_of(&message).into();
+			let outcome = T::XcmExecutor::execute_xcm_in_credit(
+				trap_origin,
+				message,
+				hash,
+				max_weight,
+				max_weight,
+			);
+			let weight_used = outcome.weight_used();
+			outcome.ensure_complete().map_err(|_| Error::<T>::RecoveryFailed)?;
+
+			Self::deposit_event(Event::AssetsRecovered {
+				origin: trap_origin,
+				assets,
+				beneficiary,
+			});
+			Ok(Some(T::WeightInfo::recover_assets().saturating_add(weight_used)).into())
+		}
+
+		/// Records that `assets` were trapped `count` times by messages from `trap_origin`, for
+		/// the traps made before this pallet recorded them.
+		///
+		/// The assets must be in the version they were trapped in, so that they are recorded under
+		/// the same hash as in `AssetTrap`. The record is removed if `count` is zero. Recording
+		/// a trap does not trap any assets, the claims are still checked by `AssetClaims`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::record_trap())]
+		pub fn record_trap(
+			origin: OriginFor<T>,
+			trap_origin: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			count: u32,
+		) -> DispatchResult {
+			T::RecoveryOrigin::ensure_origin(origin)?;
+			let trap_origin =
+				MultiLocation::try_from(*trap_origin).map_err(|()| Error::<T>::BadVersion)?;
+
+			let hash = Self::trap_hash(&trap_origin, &assets);
+			if count == 0 {
+				Traps::<T>::remove(trap_origin, hash);
+				Self::deposit_event(Event::TrapCleared { origin: trap_origin, hash });
+			} else {
+				Traps::<T>::insert(trap_origin, hash, AssetTrap { assets: *assets, count });
+				Self::deposit_event(Event::TrapRecorded { origin: trap_origin, hash, count });
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Helper to set up the recovery benchmark.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// An asset which can be deposited to a new account once trapped.
+	fn trapped_asset() -> xcm::latest::MultiAsset;
+}
+
+impl<T: Config> Pallet<T> {
+	/// The assets trapped by messages from `origin` and not claimed yet, with the hash they are
+	/// trapped under.
+	///
+	/// Assets trapped before the pallet recorded them are only included once recorded with
+	/// `record_trap`.
+	pub fn trapped_assets(origin: VersionedMultiLocation) -> Vec<(H256, AssetTrap)> {
+		match MultiLocation::try_from(origin) {
+			Ok(origin) => Traps::<T>::iter_prefix(origin).collect(),
+			Err(()) => Vec::new(),
+		}
+	}
+
+	/// The hash `assets` trapped by `origin` are trapped under, like in `pallet_xcm`.
+	fn trap_hash(origin: &MultiLocation, assets: &VersionedMultiAssets) -> H256 {
+		BlakeTwo256::hash_of(&(origin, assets))
+	}
+
+	/// The trapped assets `what` claimed with `ticket` are, like in `pallet_xcm`: the ticket is
+	/// either `Here` for the current version, or the general index of an older one.
+	fn claimed_assets(ticket: &MultiLocation, what: &MultiAssets) -> Option<VersionedMultiAssets> {
+		let versioned = VersionedMultiAssets::from(what.clone());
+		match (ticket.parents, &ticket.interior) {
+			(0, Here) => Some(versioned),
+			(0, X1(GeneralIndex(version))) => versioned.into_version(*version as u32).ok(),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> DropAssets for Pallet<T> {
+	fn drop_assets(origin: &MultiLocation, assets: Assets, context: &XcmContext) -> Weight {
+		if !assets.is_empty() {
+			let versioned = VersionedMultiAssets::from(MultiAssets::from(assets.clone()));
+			let hash = Self::trap_hash(origin, &versioned);
+			let count = Traps::<T>::mutate(origin, hash, |trap| {
+				let trap = trap.get_or_insert_with(|| AssetTrap { assets: versioned, count: 0 });
+				trap.count.saturating_inc();
+				trap.count
+			});
+			Self::deposit_event(Event::TrapRecorded { origin: *origin, hash, count });
+		}
+		T::AssetTrap::drop_assets(origin, assets, context)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
+
+impl<T: Config> ClaimAssets for Pallet<T> {
+	fn claim_assets(
+		origin: &MultiLocation,
+		ticket: &MultiLocation,
+		what: &MultiAssets,
+		context: &XcmContext,
+	) -> bool {
+		if !T::AssetClaims::claim_assets(origin, ticket, what, context) {
+			return false;
+		}
+		// Assets trapped before they were recorded are claimed without a record to update
+		if let Some(versioned) = Self::claimed_assets(ticket, what) {
+			let hash = Self::trap_hash(origin, &versioned);
+			let cleared = Traps::<T>::mutate_exists(origin, hash, |maybe_trap| match maybe_trap {
+				Some(trap) if trap.count > 1 => {
+					trap.count -= 1;
+					false
+				},
+				Some(_) => {
+					*maybe_trap = None;
+					true
+				},
+				None => false,
+			});
+			if cleared {
+				Self::deposit_event(Event::TrapCleared { origin: *origin, hash });
+			}
+		}
+		true
+	}
+}
@@ -0,0 +1,237 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{
+	assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, error::BadOrigin,
+};
+use xcm::latest::{
+	Junction::{AccountId32, Parachain},
+	MultiAsset, Parent,
+};
+
+fn sibling() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID)))
+}
+
+fn alice() -> MultiLocation {
+	AccountId32 { network: None, id: ALICE }.into()
+}
+
+fn native(amount: u128) -> MultiAssets {
+	MultiAsset::from((Here, amount)).into()
+}
+
+fn context() -> XcmContext {
+	XcmContext { origin: None, message_id: [0; 32], topic: None }
+}
+
+fn trap(origin: MultiLocation, assets: MultiAssets) {
+	TrappedAssets::drop_assets(&origin, assets.into(), &context());
+}
+
+fn recover(assets: MultiAssets, max_weight: Weight) -> DispatchResultWithPostInfo {
+	TrappedAssets::recover_assets(
+		RuntimeOrigin::root(),
+		Box::new(sibling().into()),
+		Box::new(assets.into()),
+		Box::new(alice().into()),
+		max_weight,
+	)
+}
+
+/// The hash `amount` of the native asset trapped by `origin` are trapped under.
+fn trap_hash(origin: MultiLocation, amount: u128) -> H256 {
+	BlakeTwo256::hash_of(&(origin, VersionedMultiAssets::from(native(amount))))
+}
+
+/// The weight of the recovery message: `ClaimAsset` and `DepositAsset`.
+fn recovery_weight() -> Weight {
+	UnitWeightCost::get().saturating_mul(2)
+}
+
+#[test]
+fn traps_are_recorded_by_origin() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		trap(sibling(), native(100));
+		trap(sibling(), native(50));
+		trap(Parent.into(), native(100));
+
+		let versioned = VersionedMultiAssets::from(native(100));
+		let hash = BlakeTwo256::hash_of(&(sibling(), &versioned));
+		let traps = TrappedAssets::trapped_assets(sibling().into());
+		assert_eq!(traps.len(), 2);
+		assert!(traps.contains(&(hash, AssetTrap { assets: versioned, count: 2 })));
+		// Recorded under the hash the assets are trapped under in `pallet_xcm`
+		assert_eq!(PolkadotXcm::asset_trap(hash), 2);
+
+		assert_eq!(TrappedAssets::trapped_assets(MultiLocation::parent().into()).len(), 1);
+		assert!(TrappedAssets::trapped_assets(alice().into()).is_empty());
+		System::assert_last_event(
+			Event::TrapRecorded {
+				origin: Parent.into(),
+				hash: trap_hash(Parent.into(), 100),
+				count: 1,
+			}
+			.into(),
+		);
+		System::assert_has_event(Event::TrapRecorded { origin: sibling(), hash, count: 2 }.into());
+	});
+}
+
+#[test]
+fn empty_assets_are_not_recorded() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), MultiAssets::new());
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+	});
+}
+
+#[test]
+fn claims_update_recorded_traps() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		trap(sibling(), native(100));
+		let ticket = Here.into();
+
+		assert!(TrappedAssets::claim_assets(&sibling(), &ticket, &native(100), &context()));
+		assert_eq!(TrappedAssets::trapped_assets(sibling().into())[0].1.count, 1);
+
+		assert!(TrappedAssets::claim_assets(&sibling(), &ticket, &native(100), &context()));
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+		System::assert_last_event(
+			Event::TrapCleared { origin: sibling(), hash: trap_hash(sibling(), 100) }.into(),
+		);
+
+		assert!(!TrappedAssets::claim_assets(&sibling(), &ticket, &native(100), &context()));
+	});
+}
+
+#[test]
+fn record_trap_works() {
+	new_test_ext().execute_with(|| {
+		let hash = trap_hash(sibling(), 100);
+		let versioned = VersionedMultiAssets::from(native(100));
+		assert_ok!(TrappedAssets::record_trap(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			Box::new(versioned.clone()),
+			2,
+		));
+		assert_eq!(
+			TrappedAssets::trapped_assets(sibling().into()),
+			vec![(hash, AssetTrap { assets: versioned, count: 2 })]
+		);
+		System::assert_last_event(Event::TrapRecorded { origin: sibling(), hash, count: 2 }.into());
+
+		assert_ok!(TrappedAssets::record_trap(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			Box::new(native(100).into()),
+			0,
+		));
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+		System::assert_last_event(Event::TrapCleared { origin: sibling(), hash }.into());
+	});
+}
+
+#[test]
+fn recorded_traps_are_claimed() {
+	new_test_ext().execute_with(|| {
+		// Trapped in `pallet_xcm` before it was recorded
+		PolkadotXcm::drop_assets(&sibling(), native(100).into(), &context());
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+
+		assert_ok!(TrappedAssets::record_trap(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			Box::new(native(100).into()),
+			1,
+		));
+		assert_ok!(recover(native(100), recovery_weight()));
+
+		assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 100);
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+	});
+}
+
+#[test]
+fn traps_are_recorded_by_recovery_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TrappedAssets::record_trap(
+				RuntimeOrigin::signed(ALICE.into()),
+				Box::new(sibling().into()),
+				Box::new(native(100).into()),
+				1,
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn recover_assets_works() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		let hash = BlakeTwo256::hash_of(&(sibling(), VersionedMultiAssets::from(native(100))));
+
+		assert_ok!(recover(native(100), recovery_weight()));
+
+		assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 100);
+		assert!(TrappedAssets::trapped_assets(sibling().into()).is_empty());
+		assert_eq!(PolkadotXcm::asset_trap(hash), 0);
+		System::assert_last_event(
+			Event::AssetsRecovered { origin: sibling(), assets: native(100), beneficiary: alice() }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn recover_assets_fails_for_assets_not_trapped() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		assert_noop!(recover(native(50), recovery_weight()), Error::<Test>::RecoveryFailed);
+	});
+}
+
+#[test]
+fn recover_assets_fails_without_enough_weight() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		assert_noop!(recover(native(100), UnitWeightCost::get()), Error::<Test>::RecoveryFailed);
+	});
+}
+
+#[test]
+fn assets_are_recovered_by_recovery_origin() {
+	new_test_ext().execute_with(|| {
+		trap(sibling(), native(100));
+		assert_noop!(
+			TrappedAssets::recover_assets(
+				RuntimeOrigin::signed(ALICE.into()),
+				Box::new(sibling().into()),
+				Box::new(native(100).into()),
+				Box::new(alice().into()),
+				recovery_weight(),
+			),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,235 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+// This file was originally copied from the Moonbeam batch precompile
+// and used in terms of GPLv3.
+// https://github.com/moonbeam-foundation/moonbeam/blob/master/precompiles/batch/src/lib.rs
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Context, ExitError, ExitReason, ExitRevert, Transfer};
+use pallet_evm::{Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput};
+use precompile_utils::{
+	keccak256, revert, succeed, Address, Bytes, EvmDataWriter, EvmResult, PrecompileHandleExt,
+};
+use sp_core::{H160, H256, U256};
+use sp_std::{iter::repeat, marker::PhantomData, vec, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	BatchSome = "batchSome(address[],uint256[],bytes[],uint64[])",
+	BatchSomeUntilFailure = "batchSomeUntilFailure(address[],uint256[],bytes[],uint64[])",
+	BatchAll = "batchAll(address[],uint256[],bytes[],uint64[])",
+}
+
+pub const LOG_SUBCALL_SUCCEEDED: [u8; 32] = keccak256!("SubcallSucceeded(uint256)");
+pub const LOG_SUBCALL_FAILED: [u8; 32] = keccak256!("SubcallFailed(uint256)");
+
+/// Gas charged for a subcall log: `G_LOG` + one `G_LOGTOPIC` + 32 bytes of data.
+pub const LOG_SUBCALL_GAS_COST: u64 = 375 + 375 + 8 * 32;
+
+/// Maximum number of subcalls in a batch.
+pub const ARRAY_LIMIT: usize = 2usize.pow(9);
+/// Maximum length of the call data of a subcall.
+pub const CALL_DATA_LIMIT: usize = 2usize.pow(16);
+
+/// How the batch reacts to a failing subcall.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Mode {
+	/// Keep going and execute the remaining subcalls.
+	BatchSome,
+	/// Stop at the first failure, keeping the effects of the previous subcalls.
+	BatchSomeUntilFailure,
+	/// Revert the whole batch.
+	BatchAll,
+}
+
+pub struct WatrBatchPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for WatrBatchPrecompile<R>
+where
+	R: pallet_evm::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let selector = handle.read_selector()?;
+		// Subcalls spend the value of the caller, anything sent to the precompile would be lost
+		if handle.context().apparent_value != U256::zero() {
+			return Err(revert("Function is not payable"));
+		}
+		let mode = match selector {
+			Action::BatchSome => Mode::BatchSome,
+			Action::BatchSomeUntilFailure => Mode::BatchSomeUntilFailure,
+			Action::BatchAll => Mode::BatchAll,
+		};
+		Self::batch(handle, mode)
+	}
+}
+
+impl<R> WatrBatchPrecompile<R>
+where
+	R: pallet_evm::Config,
+{
+	fn batch(handle: &mut impl PrecompileHandle, mode: Mode) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+		let (addresses, values, calls_data, gas_limits) = (
+			input.read::<Vec<Address>>()?,
+			input.read::<Vec<U256>>()?,
+			input.read::<Vec<Bytes>>()?,
+			input.read::<Vec<u64>>()?,
+		);
+
+		if addresses.len() > ARRAY_LIMIT {
+			return Err(revert("Too many subcalls"));
+		}
+		if calls_data.iter().any(|call_data| call_data.0.len() > CALL_DATA_LIMIT) {
+			return Err(revert("Subcall data too long"));
+		}
+
+		// `values`, `calls_data` and `gas_limits` may be shorter than `addresses`, in which case
+		// the missing entries use their default value. A gas limit of 0 forwards all the gas.
+		let addresses = addresses.into_iter().enumerate();
+		let values = values.into_iter().map(Some).chain(repeat(None));
+		let calls_data = calls_data.into_iter().map(Some).chain(repeat(None));
+		let gas_limits = gas_limits
+			.into_iter()
+			.map(|limit| if limit == 0 { None } else { Some(limit) })
+			.chain(repeat(None));
+
+		let caller = handle.context().caller;
+		let is_static = handle.is_static();
+
+		for ((i, address), (value, (call_data, gas_limit))) in
+			addresses.zip(values.zip(calls_data.zip(gas_limits)))
+		{
+			let address: H160 = address.into();
+			let value = value.unwrap_or_default();
+			let call_data = call_data.map(|call_data| call_data.0).unwrap_or_default();
+
+			// Keep enough gas to emit the subcall log
+			let forwarded_gas = match handle.remaining_gas().checked_sub(LOG_SUBCALL_GAS_COST) {
+				Some(remaining) => remaining,
+				None => return Self::out_of_gas(mode),
+			};
+
+			// Gas paid for the call itself, recorded by the executor when calling
+			let forwarded_gas = match forwarded_gas.checked_sub(Self::call_cost(value)) {
+				Some(remaining) => remaining,
+				None => return Self::out_of_gas(mode),
+			};
+
+			// Never forward more than the explicit gas limit, and fail if it can not be provided
+			let forwarded_gas = match gas_limit {
+				Some(limit) if limit > forwarded_gas => return Self::out_of_gas(mode),
+				Some(limit) => limit,
+				None => forwarded_gas,
+			};
+
+			// The subcall is made on behalf of the batch caller
+			let sub_context = Context { caller, address, apparent_value: value };
+			let transfer = if value.is_zero() {
+				None
+			} else {
+				Some(Transfer { source: caller, target: address, value })
+			};
+
+			let (reason, output) = handle.call(
+				address,
+				transfer,
+				call_data,
+				Some(forwarded_gas),
+				is_static,
+				&sub_context,
+			);
+
+			// Enough gas was kept for the log, so recording it can not run out of gas
+			match (reason, mode) {
+				(ExitReason::Succeed(_), _) => Self::log_subcall(handle, LOG_SUBCALL_SUCCEEDED, i)?,
+				(ExitReason::Revert(_), Mode::BatchAll) => {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output,
+					})
+				},
+				(ExitReason::Error(exit_status), Mode::BatchAll) => {
+					return Err(PrecompileFailure::Error { exit_status })
+				},
+				(ExitReason::Fatal(exit_status), _) => {
+					return Err(PrecompileFailure::Fatal { exit_status })
+				},
+				(_, Mode::BatchSomeUntilFailure) => {
+					Self::log_subcall(handle, LOG_SUBCALL_FAILED, i)?;
+					return Ok(succeed(vec![]));
+				},
+				(_, Mode::BatchSome) => Self::log_subcall(handle, LOG_SUBCALL_FAILED, i)?,
+			}
+		}
+
+		Ok(succeed(vec![]))
+	}
+
+	/// Result of the batch when there is not enough gas left for the next subcall.
+	fn out_of_gas(mode: Mode) -> EvmResult<PrecompileOutput> {
+		match mode {
+			Mode::BatchAll => Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas }),
+			Mode::BatchSome | Mode::BatchSomeUntilFailure => Ok(succeed(vec![])),
+		}
+	}
+
+	/// Emits `SubcallSucceeded` or `SubcallFailed` for the subcall at `index`.
+	fn log_subcall(
+		handle: &mut impl PrecompileHandle,
+		topic: [u8; 32],
+		index: usize,
+	) -> EvmResult<()> {
+		handle.record_cost(LOG_SUBCALL_GAS_COST)?;
+		let address = handle.code_address();
+		handle.log(
+			address,
+			vec![H256::from(topic)],
+			EvmDataWriter::new().write(U256::from(index)).build(),
+		)?;
+		Ok(())
+	}
+
+	/// Gas charged by the executor for a subcall, assuming the worst case: a cold address and a
+	/// new account. Mirrors `evm_gasometer::costs::call_cost`, which is not public.
+	fn call_cost(value: U256) -> u64 {
+		const G_CALLVALUE: u64 = 9000;
+		const G_NEWACCOUNT: u64 = 25000;
+
+		let config = <R as pallet_evm::Config>::config();
+		let transfers_value = !value.is_zero();
+
+		let access_cost = if config.increase_state_access_gas {
+			config.gas_account_access_cold
+		} else {
+			config.gas_call
+		};
+		let transfer_cost = if transfers_value { G_CALLVALUE } else { 0 };
+		// Since EIP-161 only value transfers can create a new account
+		let new_account_cost =
+			if transfers_value || config.empty_considered_exists { G_NEWACCOUNT } else { 0 };
+
+		access_cost.saturating_add(transfer_cost).saturating_add(new_account_cost)
+	}
+}
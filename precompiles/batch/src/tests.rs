@@ -0,0 +1,190 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+use frame_support::assert_ok;
+use hex_literal::hex;
+use sp_std::vec::Vec;
+
+use super::*;
+use crate::mock::*;
+
+/// Contract reverting every call: `REVERT` with 42 as output.
+const REVERTER: H160 = H160::repeat_byte(0xDE);
+const REVERTER_CODE: [u8; 10] = hex!("602a60005260206000fd");
+
+fn insert_reverter() {
+	pallet_evm::AccountCodes::<Test>::insert(REVERTER, REVERTER_CODE.to_vec());
+}
+
+fn batch_input(action: Action, subcalls: Vec<(H160, u128)>) -> Vec<u8> {
+	let (addresses, values): (Vec<_>, Vec<_>) = subcalls
+		.into_iter()
+		.map(|(address, value)| (Address(address), U256::from(value)))
+		.unzip();
+	EvmDataWriter::new_with_selector(action)
+		.write(addresses)
+		.write(values)
+		.write(Vec::<Bytes>::new())
+		.write(Vec::<u64>::new())
+		.build()
+}
+
+fn call_batch(input: Vec<u8>, value: u128) {
+	assert_ok!(Evm::call(
+		RuntimeOrigin::root(),
+		ALICE,
+		PRECOMPILE_ADDRESS,
+		input,
+		U256::from(value),
+		1_000_000,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	));
+}
+
+fn evm_events() -> Vec<pallet_evm::Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let RuntimeEvent::Evm(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+
+	System::reset_events();
+
+	result
+}
+
+/// Subcall logs emitted by the batch, as `(topic, subcall index)`.
+fn subcall_logs(events: &[pallet_evm::Event<Test>]) -> Vec<([u8; 32], U256)> {
+	events
+		.iter()
+		.filter_map(|e| match e {
+			pallet_evm::Event::Log { log } if log.address == PRECOMPILE_ADDRESS => {
+				Some((log.topics[0].0, U256::from_big_endian(&log.data)))
+			},
+			_ => None,
+		})
+		.collect()
+}
+
+fn executed_failed(events: &[pallet_evm::Event<Test>]) -> bool {
+	events.iter().any(
+		|e| matches!(e, pallet_evm::Event::ExecutedFailed { address } if *address == PRECOMPILE_ADDRESS),
+	)
+}
+
+#[test]
+fn selectors() {
+	assert_eq!(Action::BatchSome as u32, 0x79df4b9c);
+	assert_eq!(Action::BatchSomeUntilFailure as u32, 0xcf0491c7);
+	assert_eq!(Action::BatchAll as u32, 0x96e292b8);
+}
+
+#[test]
+fn batch_all_works() {
+	new_test_ext().execute_with(|| {
+		call_batch(batch_input(Action::BatchAll, vec![(BOB, 10), (CHARLIE, 20)]), 0);
+
+		assert_eq!(Balances::free_balance(BOB), 110);
+		assert_eq!(Balances::free_balance(CHARLIE), 120);
+		assert_eq!(
+			subcall_logs(&evm_events()),
+			vec![(LOG_SUBCALL_SUCCEEDED, 0.into()), (LOG_SUBCALL_SUCCEEDED, 1.into())]
+		);
+	})
+}
+
+#[test]
+fn batch_all_reverts_every_subcall_on_failure() {
+	new_test_ext().execute_with(|| {
+		insert_reverter();
+		call_batch(batch_input(Action::BatchAll, vec![(BOB, 10), (REVERTER, 0), (CHARLIE, 20)]), 0);
+
+		// The transfer to Bob done before the failing subcall is rolled back
+		assert_eq!(Balances::free_balance(BOB), 100);
+		assert_eq!(Balances::free_balance(CHARLIE), 100);
+		let events = evm_events();
+		assert!(executed_failed(&events));
+		assert!(subcall_logs(&events).is_empty());
+	})
+}
+
+#[test]
+fn batch_some_skips_failing_subcalls() {
+	new_test_ext().execute_with(|| {
+		insert_reverter();
+		call_batch(
+			batch_input(Action::BatchSome, vec![(BOB, 10), (REVERTER, 0), (CHARLIE, 20)]),
+			0,
+		);
+
+		assert_eq!(Balances::free_balance(BOB), 110);
+		assert_eq!(Balances::free_balance(CHARLIE), 120);
+		assert_eq!(
+			subcall_logs(&evm_events()),
+			vec![
+				(LOG_SUBCALL_SUCCEEDED, 0.into()),
+				(LOG_SUBCALL_FAILED, 1.into()),
+				(LOG_SUBCALL_SUCCEEDED, 2.into())
+			]
+		);
+	})
+}
+
+#[test]
+fn batch_some_until_failure_stops_at_failing_subcall() {
+	new_test_ext().execute_with(|| {
+		insert_reverter();
+		call_batch(
+			batch_input(
+				Action::BatchSomeUntilFailure,
+				vec![(BOB, 10), (REVERTER, 0), (CHARLIE, 20)],
+			),
+			0,
+		);
+
+		// Subcalls before the failure are kept, the ones after it are not executed
+		assert_eq!(Balances::free_balance(BOB), 110);
+		assert_eq!(Balances::free_balance(CHARLIE), 100);
+		assert_eq!(
+			subcall_logs(&evm_events()),
+			vec![(LOG_SUBCALL_SUCCEEDED, 0.into()), (LOG_SUBCALL_FAILED, 1.into())]
+		);
+	})
+}
+
+#[test]
+fn batch_is_not_payable() {
+	new_test_ext().execute_with(|| {
+		call_batch(batch_input(Action::BatchAll, vec![(BOB, 10)]), 1);
+
+		assert_eq!(Balances::free_balance(BOB), 100);
+		assert!(executed_failed(&evm_events()));
+	})
+}
+
+#[test]
+fn batch_fails_with_too_many_subcalls() {
+	new_test_ext().execute_with(|| {
+		let subcalls = (0..=ARRAY_LIMIT).map(|_| (BOB, 0)).collect();
+		call_batch(batch_input(Action::BatchSome, subcalls), 0);
+
+		let events = evm_events();
+		assert!(executed_failed(&events));
+		assert!(subcall_logs(&events).is_empty());
+	})
+}
@@ -12,7 +12,7 @@ use pallet_evm::{
 	AddressMapping, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 };
 use precompile_utils::{revert, succeed, EvmDataWriter, RuntimeHelper};
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
@@ -34,6 +34,31 @@ pub enum Action {
 	RemoveDIDServices = "removeDidServices(address,bytes[])",
 	IssueCredentials = "issueCredentials(address,address,string[],bytes)",
 	RevokeCredentials = "revokeCredentials(address,address,string[])",
+	VerifyDIDSignature = "verifyDidSignature(address,bytes32,bytes,uint8)",
+}
+
+/// Gas charged for recovering the signer of a signature. Same as the `ECRecover` precompile.
+pub const ECRECOVER_GAS_COST: u64 = 3_000;
+
+/// Verification relationship checked by `verifyDidSignature`.
+#[derive(Debug, PartialEq)]
+pub enum VerificationRelationship {
+	/// The DID document `authentication` method.
+	Authentication,
+	/// The DID document `assertion_method`.
+	AssertionMethod,
+}
+
+impl TryFrom<u8> for VerificationRelationship {
+	type Error = PrecompileFailure;
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0u8 => Ok(VerificationRelationship::Authentication),
+			1u8 => Ok(VerificationRelationship::AssertionMethod),
+			_ => Err(revert("Unknown verification relationship")),
+		}
+	}
 }
 
 pub struct WatrDIDPrecompile<R>(PhantomData<R>);
@@ -59,6 +84,7 @@ where
 			Action::RemoveDIDServices => Self::remove_did_services(handle),
 			Action::IssueCredentials => Self::issue_credentials(handle),
 			Action::RevokeCredentials => Self::revoke_credentials(handle),
+			Action::VerifyDIDSignature => Self::verify_did_signature(handle),
 		}
 	}
 }
@@ -270,6 +296,49 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn verify_did_signature(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		// One read for the DID document plus the signer recovery
+		handle.record_cost(
+			RuntimeHelper::<R>::db_read_gas_cost().saturating_add(ECRECOVER_GAS_COST),
+		)?;
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+		let did = R::AddressMapping::into_account_id(input.read::<Address>()?.into());
+		let hash = input.read::<H256>()?;
+		let signature = input.read::<Bytes>()?;
+		let relationship = VerificationRelationship::try_from(input.read::<u8>()?)?;
+
+		let signature: [u8; 65] = signature
+			.0
+			.try_into()
+			.map_err(|_| revert("Signature length different than 65 bytes"))?;
+
+		// A DID that does not exist can not have signed anything
+		let document = match pallet_did::Pallet::<R>::dids(R::DidIdentifier::from(did)) {
+			Some(document) => document,
+			None => return Ok(succeed(EvmDataWriter::new().write(false).build())),
+		};
+
+		let signer = match sp_io::crypto::secp256k1_ecdsa_recover(&signature, &hash.0) {
+			Ok(public) => H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..]),
+			Err(_) => return Ok(succeed(EvmDataWriter::new().write(false).build())),
+		};
+
+		// The document is read on every call, so key rotations are always respected
+		let is_valid = match relationship {
+			VerificationRelationship::Authentication => {
+				document.authentication.controller == R::AuthenticationAddress::from(signer)
+			},
+			VerificationRelationship::AssertionMethod => {
+				document.assertion_method.map_or(false, |assertion| {
+					assertion.controller == R::AssertionAddress::from(signer)
+				})
+			},
+		};
+
+		Ok(succeed(EvmDataWriter::new().write(is_valid).build()))
+	}
+
 	fn parse_services(
 		raw_services: Vec<(u8, Bytes)>,
 	) -> Result<BoundedVec<ServiceInfo<R>, R::MaxServices>, PrecompileFailure> {
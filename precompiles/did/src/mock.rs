@@ -19,13 +19,14 @@ use frame_support::sp_runtime::BuildStorage;
 use frame_support::{
 	construct_runtime, parameter_types, sp_io,
 	sp_runtime::traits::{BlakeTwo256, ConstU128, IdentityLookup},
-	traits::Everything,
+	traits::{Everything, NeverEnsureOrigin},
 	weights::Weight,
 };
 use pallet_did;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256};
+use xcm::latest::{InteriorMultiLocation, Junctions::Here, MultiLocation};
 
 use pallet_evm::{
 	EnsureAddressNever, EnsureAddressRoot, IsPrecompileResult, PrecompileResult, PrecompileSet,
@@ -41,6 +42,7 @@ parameter_types! {
 	pub const MaxCredentialTypeLength: u32 = 32;
 	pub const MaxServices: u8 = 5;
 	pub const MaxHash: u32 = 512;
+	pub UniversalLocation: InteriorMultiLocation = Here;
 }
 
 pub type AccountId = TestAccount;
@@ -173,6 +175,11 @@ impl pallet_did::Config for Test {
 	type MaxCredentialsTypes = MaxCredentialsTypes;
 	type MaxCredentialTypeLength = MaxCredentialTypeLength;
 	type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type CredentialQueryOrigin = NeverEnsureOrigin<MultiLocation>;
+	type XcmSender = ();
+	type UniversalLocation = UniversalLocation;
+	type XcmDidOrigin = NeverEnsureOrigin<MultiLocation>;
+	type LocationToAccountId = ();
 	type WeightInfo = ();
 }
 
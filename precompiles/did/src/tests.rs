@@ -19,7 +19,7 @@ use pallet_did::{
 	ServiceKeysOf,
 };
 use precompile_utils::testing::PrecompileTesterExt;
-use sp_core::{bounded_vec, H160};
+use sp_core::{bounded_vec, ecdsa, Pair, H160};
 use sp_std::vec::Vec;
 
 use super::*;
@@ -599,3 +599,197 @@ fn revert_revoke_credentials_if_too_many() {
 			});
 	});
 }
+
+fn eth_address(pair: &ecdsa::Pair) -> H160 {
+	let message = [0u8; 32];
+	let signature = pair.sign_prehashed(&message);
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+	H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..])
+}
+
+fn insert_did_with_keys(controller: TestAccount, authentication: H160, assertion: H160) {
+	assert_ok!(DID::create_did(
+		RuntimeOrigin::signed(controller.clone()),
+		controller,
+		authentication,
+		Some(assertion),
+		default_services()
+	));
+}
+
+fn verify_did_signature_input(
+	did: TestAccount,
+	hash: H256,
+	signature: ecdsa::Signature,
+	relationship: u8,
+) -> Vec<u8> {
+	EvmDataWriter::new_with_selector(Action::VerifyDIDSignature)
+		.write(Address(did.into()))
+		.write(hash)
+		.write(Bytes(signature.0.to_vec()))
+		.write(relationship)
+		.build()
+}
+
+#[test]
+fn it_verifies_did_signatures() {
+	new_test_ext().execute_with(|| {
+		let authentication = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let assertion = ecdsa::Pair::from_seed(&[2u8; 32]);
+		insert_did_with_keys(
+			TestAccount::Alice,
+			eth_address(&authentication),
+			eth_address(&assertion),
+		);
+		let hash = H256::repeat_byte(0x42);
+
+		// Authentication key matches authentication relationship
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					authentication.sign_prehashed(&hash.0),
+					0u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// Assertion key matches assertion relationship
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					assertion.sign_prehashed(&hash.0),
+					1u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// Assertion key does not match authentication relationship
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					assertion.sign_prehashed(&hash.0),
+					0u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(false).build());
+	});
+}
+
+#[test]
+fn it_respects_did_key_rotation() {
+	new_test_ext().execute_with(|| {
+		let old_key = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let new_key = ecdsa::Pair::from_seed(&[3u8; 32]);
+		insert_did_with_keys(TestAccount::Alice, eth_address(&old_key), H160::from([1u8; 20]));
+		assert_ok!(DID::update_did(
+			RuntimeOrigin::signed(TestAccount::Alice),
+			TestAccount::Alice,
+			None,
+			Some(eth_address(&new_key)),
+			None,
+			None
+		));
+		let hash = H256::repeat_byte(0x42);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					old_key.sign_prehashed(&hash.0),
+					0u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(false).build());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					new_key.sign_prehashed(&hash.0),
+					0u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+	});
+}
+
+#[test]
+fn it_does_not_verify_signatures_of_unknown_did() {
+	new_test_ext().execute_with(|| {
+		let key = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let hash = H256::repeat_byte(0x42);
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					key.sign_prehashed(&hash.0),
+					0u8,
+				),
+			)
+			.execute_returns(EvmDataWriter::new().write(false).build());
+	});
+}
+
+#[test]
+fn reverts_verify_did_signature_if_invalid_input() {
+	new_test_ext().execute_with(|| {
+		let key = ecdsa::Pair::from_seed(&[1u8; 32]);
+		insert_did_with_keys(TestAccount::Alice, eth_address(&key), H160::from([1u8; 20]));
+		let hash = H256::repeat_byte(0x42);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				verify_did_signature_input(
+					TestAccount::Alice,
+					hash,
+					key.sign_prehashed(&hash.0),
+					2u8,
+				),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Unknown verification relationship");
+				true
+			});
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::VerifyDIDSignature)
+					.write(Address(TestAccount::Alice.into()))
+					.write(hash)
+					.write(Bytes(vec![1u8; 64]))
+					.write(0u8)
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Signature length different than 65 bytes");
+				true
+			});
+	});
+}
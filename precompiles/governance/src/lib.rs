@@ -0,0 +1,315 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::Get,
+	BoundedVec,
+};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileOutput};
+use parity_scale_codec::{DecodeLimit, Encode, MaxEncodedLen};
+use precompile_utils::{
+	revert, succeed, Address, Bytes, EvmDataWriter, EvmResult, PrecompileHandleExt, RuntimeHelper,
+};
+use sp_core::{H160, H256};
+use sp_runtime::traits::Hash;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	Propose = "propose(uint32,bytes)",
+	Vote = "vote(bytes32,uint32,bool)",
+	Close = "close(bytes32,uint32)",
+	ProposalHash = "proposalHash(bytes)",
+	Proposals = "proposals()",
+	Members = "members()",
+	IsMember = "isMember(address)",
+	Prime = "prime()",
+	EncodeSimpleMajority = "encodeSimpleMajority(bytes)",
+	EncodeSuperMajority = "encodeSuperMajority(bytes)",
+	EncodeUnanimous = "encodeUnanimous(bytes)",
+}
+
+/// Maximum nesting depth when decoding a SCALE encoded call.
+pub const CALL_DECODE_DEPTH_LIMIT: u32 = 8;
+
+/// Index returned by `propose` when the proposal was executed right away instead of being stored,
+/// which happens for thresholds below 2.
+pub const EXECUTED_PROPOSAL_INDEX: u32 = u32::MAX;
+
+/// Gas charged for every 32 byte word of a storage item, on top of the db read. Same as the cold
+/// `SLOAD` cost of EIP-2929.
+pub const STORAGE_WORD_GAS_COST: u64 = 2_100;
+
+/// Resolves an account back to the EVM address `pallet_evm::Config::AddressMapping` maps to it.
+///
+/// Address mappings are usually one way hashes, so implementations must only return an address
+/// they can check maps back to `account`.
+pub trait AccountToAddress<AccountId> {
+	/// The address mapped to `account`, if it is known.
+	fn address_of(account: &AccountId) -> Option<H160>;
+}
+
+/// Motion used to dispatch a proposal with `Root` origin.
+#[derive(Debug, PartialEq)]
+pub enum MotionKind {
+	SimpleMajority,
+	SuperMajority,
+	Unanimous,
+}
+
+/// Precompile exposing the collective `I` and `pallet_motion` to EVM accounts.
+///
+/// Accounts are the ones `R::AddressMapping` maps EVM addresses to, so an Ethereum key holder
+/// takes part in the collective once its mapped account is a member. `A` resolves members back to
+/// their address; members it can not resolve are returned as the zero address.
+pub struct WatrGovernancePrecompile<R, I, A>(PhantomData<(R, I, A)>);
+
+impl<R, I, A> Precompile for WatrGovernancePrecompile<R, I, A>
+where
+	R: pallet_evm::Config + pallet_collective::Config<I> + pallet_motion::Config,
+	I: 'static,
+	A: AccountToAddress<R::AccountId>,
+	<R as frame_system::pallet::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<Option<R::AccountId>>,
+	<R as frame_system::Config>::RuntimeCall:
+		From<pallet_collective::Call<R, I>> + From<pallet_motion::Call<R>>,
+	<R as frame_system::Config>::Hash: From<H256> + Into<H256>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let selector = handle.read_selector()?;
+		match selector {
+			Action::Propose => Self::propose(handle),
+			Action::Vote => Self::vote(handle),
+			Action::Close => Self::close(handle),
+			Action::ProposalHash => Self::proposal_hash(handle),
+			Action::Proposals => Self::proposals(handle),
+			Action::Members => Self::members(handle),
+			Action::IsMember => Self::is_member(handle),
+			Action::Prime => Self::prime(handle),
+			Action::EncodeSimpleMajority => Self::encode_motion(handle, MotionKind::SimpleMajority),
+			Action::EncodeSuperMajority => Self::encode_motion(handle, MotionKind::SuperMajority),
+			Action::EncodeUnanimous => Self::encode_motion(handle, MotionKind::Unanimous),
+		}
+	}
+}
+
+impl<R, I, A> WatrGovernancePrecompile<R, I, A>
+where
+	R: pallet_evm::Config + pallet_collective::Config<I> + pallet_motion::Config,
+	I: 'static,
+	A: AccountToAddress<R::AccountId>,
+	<R as frame_system::pallet::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<Option<R::AccountId>>,
+	<R as frame_system::Config>::RuntimeCall:
+		From<pallet_collective::Call<R, I>> + From<pallet_motion::Call<R>>,
+	<R as frame_system::Config>::Hash: From<H256> + Into<H256>,
+{
+	fn propose(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let threshold = input.read::<u32>()?;
+		let proposal = Self::decode_proposal(input.read::<Bytes>()?)?;
+		let length_bound = proposal.encoded_size() as u32;
+
+		let proposal_index = pallet_collective::Pallet::<R, I>::proposal_count();
+		let origin = Some(R::AddressMapping::into_account_id(handle.context().caller));
+		RuntimeHelper::<R>::try_dispatch(
+			handle,
+			origin.into(),
+			pallet_collective::Call::<R, I>::propose {
+				threshold,
+				proposal: Box::new(proposal),
+				length_bound,
+			},
+		)?;
+
+		// The proposal is only stored, and the count bumped, when it is not executed right away
+		let proposal_index = if pallet_collective::Pallet::<R, I>::proposal_count() > proposal_index
+		{
+			proposal_index
+		} else {
+			EXECUTED_PROPOSAL_INDEX
+		};
+
+		Ok(succeed(EvmDataWriter::new().write(proposal_index).build()))
+	}
+
+	fn vote(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let (proposal, index, approve) =
+			(input.read::<H256>()?, input.read::<u32>()?, input.read::<bool>()?);
+
+		let origin = Some(R::AddressMapping::into_account_id(handle.context().caller));
+		RuntimeHelper::<R>::try_dispatch(
+			handle,
+			origin.into(),
+			pallet_collective::Call::<R, I>::vote { proposal: proposal.into(), index, approve },
+		)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn close(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let (proposal_hash, index) = (input.read::<H256>()?, input.read::<u32>()?);
+
+		// The bounds are taken from the stored proposal so EVM callers do not have to provide them
+		let proposal = match pallet_collective::Pallet::<R, I>::proposal_of(
+			<R as frame_system::Config>::Hash::from(proposal_hash),
+		) {
+			Some(proposal) => proposal,
+			None => return Err(revert("Proposal does not exist")),
+		};
+		let proposal_weight_bound = proposal.get_dispatch_info().weight;
+		let length_bound = proposal.encoded_size() as u32;
+
+		let origin = Some(R::AddressMapping::into_account_id(handle.context().caller));
+		RuntimeHelper::<R>::try_dispatch(
+			handle,
+			origin.into(),
+			pallet_collective::Call::<R, I>::close {
+				proposal_hash: proposal_hash.into(),
+				index,
+				proposal_weight_bound,
+				length_bound,
+			},
+		)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn proposal_hash(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let proposal = Self::decode_proposal(input.read::<Bytes>()?)?;
+		let hash: H256 = <R as frame_system::Config>::Hashing::hash_of(&proposal).into();
+
+		Ok(succeed(EvmDataWriter::new().write(hash).build()))
+	}
+
+	fn proposals(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(Self::storage_read_gas_cost(BoundedVec::<
+			<R as frame_system::Config>::Hash,
+			<R as pallet_collective::Config<I>>::MaxProposals,
+		>::max_encoded_len()))?;
+		let proposals: Vec<H256> = pallet_collective::Pallet::<R, I>::proposals()
+			.into_iter()
+			.map(Into::into)
+			.collect();
+
+		Ok(succeed(EvmDataWriter::new().write(proposals).build()))
+	}
+
+	fn members(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let max_members = <R as pallet_collective::Config<I>>::MaxMembers::get() as usize;
+		handle.record_cost(Self::storage_read_gas_cost(
+			R::AccountId::max_encoded_len().saturating_mul(max_members),
+		))?;
+		let members = pallet_collective::Pallet::<R, I>::members();
+
+		// One more read for every member resolved to its address
+		handle.record_cost(
+			RuntimeHelper::<R>::db_read_gas_cost().saturating_mul(members.len() as u64),
+		)?;
+		let members: Vec<Address> = members
+			.iter()
+			.map(|member| Address(A::address_of(member).unwrap_or_default()))
+			.collect();
+
+		Ok(succeed(EvmDataWriter::new().write(members).build()))
+	}
+
+	fn is_member(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let who = R::AddressMapping::into_account_id(input.read::<Address>()?.into());
+		let is_member = pallet_collective::Pallet::<R, I>::is_member(&who);
+
+		Ok(succeed(EvmDataWriter::new().write(is_member).build()))
+	}
+
+	fn prime(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		// One read for the prime member, one to resolve its address
+		handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost().saturating_mul(2))?;
+		// The zero address is returned when there is no prime member
+		let prime = Address(
+			pallet_collective::Pallet::<R, I>::prime()
+				.and_then(|prime| A::address_of(&prime))
+				.unwrap_or_default(),
+		);
+
+		Ok(succeed(EvmDataWriter::new().write(prime).build()))
+	}
+
+	/// Wraps the SCALE encoded call in a `pallet_motion` call, ready to be passed to `propose`.
+	fn encode_motion(
+		handle: &mut impl PrecompileHandle,
+		kind: MotionKind,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let call = Box::new(
+			<R as pallet_motion::Config>::RuntimeCall::decode_with_depth_limit(
+				CALL_DECODE_DEPTH_LIMIT,
+				&mut &input.read::<Bytes>()?.0[..],
+			)
+			.map_err(|_| revert("Failed to decode call"))?,
+		);
+		let motion = match kind {
+			MotionKind::SimpleMajority => pallet_motion::Call::<R>::simple_majority { call },
+			MotionKind::SuperMajority => pallet_motion::Call::<R>::super_majority { call },
+			MotionKind::Unanimous => pallet_motion::Call::<R>::unanimous { call },
+		};
+		let encoded = <R as frame_system::Config>::RuntimeCall::from(motion).encode();
+
+		Ok(succeed(EvmDataWriter::new().write(Bytes(encoded)).build()))
+	}
+
+	/// Gas for reading a storage item of at most `max_encoded_len` bytes.
+	fn storage_read_gas_cost(max_encoded_len: usize) -> u64 {
+		let words = (max_encoded_len as u64).saturating_add(31) / 32;
+		RuntimeHelper::<R>::db_read_gas_cost()
+			.saturating_add(words.saturating_mul(STORAGE_WORD_GAS_COST))
+	}
+
+	fn decode_proposal(
+		proposal: Bytes,
+	) -> EvmResult<<R as pallet_collective::Config<I>>::Proposal> {
+		<R as pallet_collective::Config<I>>::Proposal::decode_with_depth_limit(
+			CALL_DECODE_DEPTH_LIMIT,
+			&mut &proposal.0[..],
+		)
+		.map_err(|_| revert("Failed to decode proposal"))
+	}
+}
@@ -0,0 +1,301 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::{
+		traits::{BlakeTwo256, ConstU128, ConstU32, ConstU64, IdentityLookup},
+		BuildStorage, Perbill,
+	},
+	traits::Everything,
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_evm::{
+	EnsureAddressNever, EnsureAddressRoot, IsPrecompileResult, PrecompileResult, PrecompileSet,
+};
+use parity_scale_codec::{Decode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+pub type AccountId = TestAccount;
+pub type Balance = u128;
+pub type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const PRECOMPILE_ADDRESS: H160 = H160::repeat_byte(0xCE);
+
+/// A simple account type.
+#[derive(
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Clone,
+	Encode,
+	Decode,
+	Debug,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+	derive_more::Display,
+	TypeInfo,
+)]
+pub enum TestAccount {
+	Alice,
+	Bob,
+	Charlie,
+	Bogus,
+}
+
+impl Default for TestAccount {
+	fn default() -> Self {
+		Self::Alice
+	}
+}
+
+impl AddressMapping<TestAccount> for TestAccount {
+	fn into_account_id(h160_account: H160) -> TestAccount {
+		match h160_account {
+			a if a == H160::repeat_byte(0xAA) => Self::Alice,
+			a if a == H160::repeat_byte(0xBB) => Self::Bob,
+			a if a == H160::repeat_byte(0xCC) => Self::Charlie,
+			_ => Self::Bogus,
+		}
+	}
+}
+
+impl From<H160> for TestAccount {
+	fn from(x: H160) -> TestAccount {
+		TestAccount::into_account_id(x)
+	}
+}
+
+impl From<TestAccount> for H160 {
+	fn from(value: TestAccount) -> H160 {
+		match value {
+			TestAccount::Alice => H160::repeat_byte(0xAA),
+			TestAccount::Bob => H160::repeat_byte(0xBB),
+			TestAccount::Charlie => H160::repeat_byte(0xCC),
+			TestAccount::Bogus => H160::repeat_byte(0xDD),
+		}
+	}
+}
+
+impl AccountToAddress<TestAccount> for TestAccount {
+	fn address_of(account: &TestAccount) -> Option<H160> {
+		match account {
+			TestAccount::Bogus => None,
+			account => Some(account.clone().into()),
+		}
+	}
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 19;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(Weight::MAX);
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u32;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const PrecompilesValue: TestPrecompileSet<Test> =
+	TestPrecompileSet(PhantomData);
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type PrecompilesType = TestPrecompileSet<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub CouncilMotionDuration: u64 = 7;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+	pub MaxProposalWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+pub type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = ();
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxProposalWeight;
+}
+
+impl pallet_motion::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type SimpleMajorityOrigin =
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+	type SuperMajorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	type UnanimousOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SimpleMajorityDelay = ConstU64<0>;
+	type SuperMajorityDelay = ConstU64<0>;
+	type UnanimousDelay = ConstU64<0>;
+	type SimpleMajorityCalls = Everything;
+	type SuperMajorityCalls = Everything;
+	type UnanimousCalls = Everything;
+	type VetoOrigin = EnsureRoot<AccountId>;
+	type MaxReasonLength = ConstU32<256>;
+	type WeightInfo = ();
+	type MaxBatchCalls = ConstU32<16>;
+	type MotionOrigin = pallet_motion::AsMotionOrigin<RuntimeOrigin>;
+	type CollectiveProposals = pallet_motion::CollectiveProposalIndex<Test, CouncilCollective>;
+	type MaxHistoryLength = ConstU32<16>;
+	type FastTrackOrigin = EnsureRoot<AccountId>;
+	type FastTrackCalls = Everything;
+	type CancellationRetention = ConstU64<5>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TestPrecompileSet<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for TestPrecompileSet<R>
+where
+	WatrGovernancePrecompile<R, CouncilCollective, TestAccount>: Precompile,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		match handle.code_address() {
+			a if a == PRECOMPILE_ADDRESS => {
+				Some(WatrGovernancePrecompile::<R, CouncilCollective, TestAccount>::execute(handle))
+			},
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer { is_precompile: address == PRECOMPILE_ADDRESS, extra_cost: 0 }
+	}
+}
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Evm: pallet_evm,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Council: pallet_collective<Instance1>,
+		Motion: pallet_motion,
+	}
+);
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = <frame_system::GenesisConfig<Test> as BuildStorage>::build_storage(
+		&frame_system::GenesisConfig::default(),
+	)
+	.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(TestAccount::Alice, 100),
+			(TestAccount::Bob, 100),
+			(TestAccount::Charlie, 100),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_collective::GenesisConfig::<Test, CouncilCollective> {
+		members: vec![TestAccount::Alice, TestAccount::Bob, TestAccount::Charlie],
+		phantom: Default::default(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
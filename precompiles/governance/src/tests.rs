@@ -0,0 +1,341 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+use frame_support::assert_ok;
+use precompile_utils::testing::PrecompileTesterExt;
+use sp_runtime::traits::BlakeTwo256;
+
+use super::*;
+use crate::mock::*;
+
+fn precompiles() -> TestPrecompileSet<Test> {
+	PrecompilesValue::get()
+}
+
+fn set_balance_call() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+		who: TestAccount::Bogus,
+		new_free: 50,
+	})
+}
+
+fn propose(who: TestAccount, threshold: u32, proposal: &RuntimeCall, expected_index: u32) {
+	precompiles()
+		.prepare_test(
+			who,
+			PRECOMPILE_ADDRESS,
+			EvmDataWriter::new_with_selector(Action::Propose)
+				.write(threshold)
+				.write(Bytes(proposal.encode()))
+				.build(),
+		)
+		.execute_returns(EvmDataWriter::new().write(expected_index).build());
+}
+
+fn vote(who: TestAccount, proposal_hash: H256, index: u32, approve: bool) {
+	precompiles()
+		.prepare_test(
+			who,
+			PRECOMPILE_ADDRESS,
+			EvmDataWriter::new_with_selector(Action::Vote)
+				.write(proposal_hash)
+				.write(index)
+				.write(approve)
+				.build(),
+		)
+		.execute_returns(EvmDataWriter::new().write(true).build());
+}
+
+#[test]
+fn members_works() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Members).build(),
+			)
+			.execute_returns(
+				EvmDataWriter::new()
+					.write(vec![
+						Address(H160::repeat_byte(0xAA)),
+						Address(H160::repeat_byte(0xBB)),
+						Address(H160::repeat_byte(0xCC)),
+					])
+					.build(),
+			);
+	});
+}
+
+#[test]
+fn members_without_known_address_are_zero() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Council::set_members(
+			RuntimeOrigin::root(),
+			vec![TestAccount::Alice, TestAccount::Bogus],
+			None,
+			2
+		));
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Members).build(),
+			)
+			.execute_returns(
+				EvmDataWriter::new()
+					.write(vec![Address(H160::repeat_byte(0xAA)), Address(H160::zero())])
+					.build(),
+			);
+	});
+}
+
+#[test]
+fn is_member_works() {
+	new_test_ext().execute_with(|| {
+		for (who, is_member) in [(TestAccount::Alice, true), (TestAccount::Bogus, false)] {
+			precompiles()
+				.prepare_test(
+					TestAccount::Bogus,
+					PRECOMPILE_ADDRESS,
+					EvmDataWriter::new_with_selector(Action::IsMember)
+						.write(Address(who.into()))
+						.build(),
+				)
+				.execute_returns(EvmDataWriter::new().write(is_member).build());
+		}
+	});
+}
+
+#[test]
+fn prime_is_zero_when_not_set() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Prime).build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(Address(H160::zero())).build());
+	});
+}
+
+#[test]
+fn prime_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Council::set_members(
+			RuntimeOrigin::root(),
+			vec![TestAccount::Alice, TestAccount::Bob],
+			Some(TestAccount::Bob),
+			2
+		));
+
+		// `isMember` accepts the returned address
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Prime).build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(Address(H160::repeat_byte(0xBB))).build());
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::IsMember)
+					.write(Address(H160::repeat_byte(0xBB)))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+	});
+}
+
+#[test]
+fn propose_works() {
+	new_test_ext().execute_with(|| {
+		let proposal_hash = BlakeTwo256::hash_of(&set_balance_call());
+		propose(TestAccount::Alice, 2, &set_balance_call(), 0);
+
+		assert_eq!(Council::proposals().into_inner(), vec![proposal_hash]);
+		assert_eq!(Council::proposal_of(proposal_hash), Some(set_balance_call()));
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Proposals).build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(vec![proposal_hash]).build());
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::ProposalHash)
+					.write(Bytes(set_balance_call().encode()))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(proposal_hash).build());
+	});
+}
+
+#[test]
+fn propose_returns_sentinel_when_executed_right_away() {
+	new_test_ext().execute_with(|| {
+		propose(TestAccount::Alice, 1, &set_balance_call(), EXECUTED_PROPOSAL_INDEX);
+
+		assert!(Council::proposals().is_empty());
+		assert_eq!(Council::proposal_count(), 0);
+
+		// The next stored proposal still gets the first index
+		propose(TestAccount::Alice, 2, &set_balance_call(), 0);
+	});
+}
+
+#[test]
+fn proposals_are_charged_for_max_encoded_size() {
+	new_test_ext().execute_with(|| {
+		// 100 proposal hashes and their length prefix span 101 storage words
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Proposals).build(),
+			)
+			.expect_cost(101 * STORAGE_WORD_GAS_COST)
+			.execute_returns(EvmDataWriter::new().write(Vec::<H256>::new()).build());
+	});
+}
+
+#[test]
+fn propose_fails_for_non_members() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Bogus,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Propose)
+					.write(2u32)
+					.write(Bytes(set_balance_call().encode()))
+					.build(),
+			)
+			.execute_reverts(|_| true);
+
+		assert!(Council::proposals().is_empty());
+	});
+}
+
+#[test]
+fn propose_fails_with_invalid_proposal() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Propose)
+					.write(2u32)
+					.write(Bytes(vec![0xFF, 0xFF]))
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Failed to decode proposal");
+				true
+			});
+	});
+}
+
+#[test]
+fn motion_can_be_proposed_voted_and_closed() {
+	new_test_ext().execute_with(|| {
+		let proposal = RuntimeCall::Motion(pallet_motion::Call::simple_majority {
+			call: Box::new(set_balance_call()),
+		});
+		let proposal_hash = BlakeTwo256::hash_of(&proposal);
+		propose(TestAccount::Alice, 2, &proposal, 0);
+		vote(TestAccount::Alice, proposal_hash, 0, true);
+		vote(TestAccount::Bob, proposal_hash, 0, true);
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Charlie,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Close)
+					.write(proposal_hash)
+					.write(0u32)
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// The motion dispatched the wrapped call with `Root` origin
+		assert!(Council::proposals().is_empty());
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Motion(pallet_motion::Event::DispatchSimpleMajority {
+				motion_result: Ok(())
+			})));
+		assert_eq!(Balances::free_balance(TestAccount::Bogus), 50);
+	});
+}
+
+#[test]
+fn close_fails_for_unknown_proposal() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::Close)
+					.write(H256::repeat_byte(1))
+					.write(0u32)
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Proposal does not exist");
+				true
+			});
+	});
+}
+
+#[test]
+fn encode_motion_works() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(set_balance_call());
+		for (action, motion) in [
+			(
+				Action::EncodeSimpleMajority,
+				pallet_motion::Call::<Test>::simple_majority { call: call.clone() },
+			),
+			(
+				Action::EncodeSuperMajority,
+				pallet_motion::Call::<Test>::super_majority { call: call.clone() },
+			),
+			(
+				Action::EncodeUnanimous,
+				pallet_motion::Call::<Test>::unanimous { call: call.clone() },
+			),
+		] {
+			precompiles()
+				.prepare_test(
+					TestAccount::Bogus,
+					PRECOMPILE_ADDRESS,
+					EvmDataWriter::new_with_selector(action).write(Bytes(call.encode())).build(),
+				)
+				.execute_returns(
+					EvmDataWriter::new().write(Bytes(RuntimeCall::Motion(motion).encode())).build(),
+				);
+		}
+	});
+}
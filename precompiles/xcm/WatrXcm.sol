@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+pragma solidity ^0.8.0;
+
+/// Cross-chain transfers of native WATR and XC-20s.
+///
+/// Transfers revert if the message is not allowed. The outcome of its execution is reported in
+/// the `PolkadotXcm.Attempted` event.
+interface WatrXcm {
+	/// Sends `amount` of native WATR to the 32 byte account `beneficiary` on the sibling parachain `parachainId`.
+	function transferNative(uint32 parachainId, uint256 amount, bytes32 beneficiary) external returns (bool);
+	/// Sends `amount` of native WATR to the Ethereum account `beneficiary` on the sibling parachain `parachainId`.
+	function transferNativeToEvm(uint32 parachainId, uint256 amount, address beneficiary) external returns (bool);
+	/// Sends `amount` of the XC-20 `asset` back to its reserve chain, for the 32 byte account `beneficiary`.
+	function transferAsset(address asset, uint256 amount, bytes32 beneficiary) external returns (bool);
+	/// Sends `amount` of the XC-20 `asset` back to its reserve chain, for the Ethereum account `beneficiary`.
+	function transferAssetToEvm(address asset, uint256 amount, address beneficiary) external returns (bool);
+}
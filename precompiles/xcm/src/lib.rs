@@ -0,0 +1,206 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::{Contains, EnsureOrigin, Get},
+};
+use pallet_evm::{
+	AddressMapping, GasWeightMapping, Precompile, PrecompileHandle, PrecompileOutput,
+};
+use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use pallet_xc_asset_config::XcAssetLocation;
+use parity_scale_codec::Encode;
+use precompile_utils::{
+	revert, succeed, Address, EvmDataReader, EvmDataWriter, EvmResult, PrecompileHandleExt,
+	RuntimeHelper,
+};
+use sp_core::{hashing::blake2_256, H256, U256};
+use sp_std::marker::PhantomData;
+use watr_common::xcm_config::{send_native_to_sibling_message, send_to_reserve_message};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::WeightBounds;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	TransferNative = "transferNative(uint32,uint256,bytes32)",
+	TransferNativeToEvm = "transferNativeToEvm(uint32,uint256,address)",
+	TransferAsset = "transferAsset(address,uint256,bytes32)",
+	TransferAssetToEvm = "transferAssetToEvm(address,uint256,address)",
+}
+
+/// Kind of account receiving a transfer on the destination chain.
+#[derive(Debug, PartialEq)]
+pub enum BeneficiaryKind {
+	/// A 32 byte account, read as `bytes32`.
+	AccountId32,
+	/// An Ethereum account, read as `address`.
+	AccountKey20,
+}
+
+/// Precompile sending native WATR and XC-20s to other chains.
+///
+/// Messages are executed by the `XcmExecutor` of `pallet_xcm` on behalf of the account the caller
+/// maps to, under the `ExecuteXcmOrigin` and `XcmExecuteFilter` of the runtime, and the call
+/// reverts unless the message completes. Native WATR is sent to sibling parachains, XC-20s are
+/// sent back to their reserve, be it a sibling or the relay chain.
+pub struct WatrXcmPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for WatrXcmPrecompile<R>
+where
+	R: pallet_evm::Config
+		+ pallet_xcm::Config
+		+ pallet_xc_asset_config::Config
+		+ AddressToAssetId<<R as pallet_xc_asset_config::Config>::AssetId>,
+	<R as frame_system::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<Option<R::AccountId>>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let selector = handle.read_selector()?;
+		match selector {
+			Action::TransferNative => Self::transfer_native(handle, BeneficiaryKind::AccountId32),
+			Action::TransferNativeToEvm => {
+				Self::transfer_native(handle, BeneficiaryKind::AccountKey20)
+			},
+			Action::TransferAsset => Self::transfer_asset(handle, BeneficiaryKind::AccountId32),
+			Action::TransferAssetToEvm => {
+				Self::transfer_asset(handle, BeneficiaryKind::AccountKey20)
+			},
+		}
+	}
+}
+
+impl<R> WatrXcmPrecompile<R>
+where
+	R: pallet_evm::Config
+		+ pallet_xcm::Config
+		+ pallet_xc_asset_config::Config
+		+ AddressToAssetId<<R as pallet_xc_asset_config::Config>::AssetId>,
+	<R as frame_system::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<Option<R::AccountId>>,
+{
+	fn transfer_native(
+		handle: &mut impl PrecompileHandle,
+		kind: BeneficiaryKind,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let para_id = input.read::<u32>()?;
+		let amount = Self::read_amount(&mut input)?;
+		let beneficiary = Self::read_beneficiary(&mut input, kind)?;
+
+		let message = send_native_to_sibling_message(
+			para_id,
+			amount,
+			beneficiary,
+			<R as pallet_xcm::Config>::UniversalLocation::get(),
+		)
+		.ok_or_else(|| revert("Invalid destination"))?;
+
+		Self::execute_message(handle, message)
+	}
+
+	fn transfer_asset(
+		handle: &mut impl PrecompileHandle,
+		kind: BeneficiaryKind,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let asset = input.read::<Address>()?;
+		let amount = Self::read_amount(&mut input)?;
+		let beneficiary = Self::read_beneficiary(&mut input, kind)?;
+
+		let asset_id =
+			R::address_to_asset_id(asset.into()).ok_or_else(|| revert("Unknown asset"))?;
+		let asset_location = pallet_xc_asset_config::Pallet::<R>::get_xc_asset_location(asset_id)
+			.ok_or_else(|| revert("Asset is not cross-chain"))?;
+		let message = send_to_reserve_message(
+			asset_location,
+			amount,
+			beneficiary,
+			<R as pallet_xcm::Config>::UniversalLocation::get(),
+		)
+		.ok_or_else(|| revert("Asset has no reserve"))?;
+
+		Self::execute_message(handle, message)
+	}
+
+	/// Executes `message` on behalf of the caller, with the weight the runtime weighs it at.
+	///
+	/// The executor is run directly rather than through `pallet_xcm::execute`, which succeeds even
+	/// when the outcome is incomplete, e.g. when the assets could not be withdrawn or were trapped.
+	fn execute_message(
+		handle: &mut impl PrecompileHandle,
+		mut message: Xcm<<R as pallet_xcm::Config>::RuntimeCall>,
+	) -> EvmResult<PrecompileOutput> {
+		let max_weight = <R as pallet_xcm::Config>::Weigher::weight(&mut message)
+			.map_err(|_| revert("Failed to weigh message"))?;
+		handle
+			.record_cost(<R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(max_weight))?;
+
+		let caller = R::AddressMapping::into_account_id(handle.context().caller);
+		let origin_location = <R as pallet_xcm::Config>::ExecuteXcmOrigin::ensure_origin(
+			frame_system::RawOrigin::Signed(caller).into(),
+		)
+		.map_err(|_| revert("Caller cannot execute XCM"))?;
+		if !<R as pallet_xcm::Config>::XcmExecuteFilter::contains(&(
+			origin_location,
+			message.clone(),
+		)) {
+			return Err(revert("Message is filtered"));
+		}
+
+		let hash = message.using_encoded(blake2_256);
+		let outcome = <R as pallet_xcm::Config>::XcmExecutor::execute_xcm_in_credit(
+			origin_location,
+			message,
+			hash,
+			max_weight,
+			max_weight,
+		);
+		outcome.ensure_complete().map_err(|_| revert("XCM execution incomplete"))?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn read_amount(input: &mut EvmDataReader) -> EvmResult<u128> {
+		u128::try_from(input.read::<U256>()?).map_err(|_| revert("Amount is too large"))
+	}
+
+	fn read_beneficiary(input: &mut EvmDataReader, kind: BeneficiaryKind) -> EvmResult<Junction> {
+		Ok(match kind {
+			BeneficiaryKind::AccountId32 => {
+				AccountId32 { network: None, id: input.read::<H256>()?.to_fixed_bytes() }
+			},
+			BeneficiaryKind::AccountKey20 => {
+				AccountKey20 { network: None, key: input.read::<Address>()?.0.to_fixed_bytes() }
+			},
+		})
+	}
+}
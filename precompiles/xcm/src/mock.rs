@@ -0,0 +1,431 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::{
+		traits::{BlakeTwo256, ConstU128, ConstU32, IdentityLookup},
+		BuildStorage,
+	},
+	traits::{Everything, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_evm::{
+	EnsureAddressNever, EnsureAddressRoot, IsPrecompileResult, PrecompileResult, PrecompileSet,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+use watr_common::xcm_config::{
+	AccountBeneficiaries, AllowOnlySendNativeToSiblings, AllowOnlySendToReservePerAsset,
+	DenyReserveDepositToRelayChain,
+};
+use xcm_builder::{
+	CurrencyAdapter, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds, IsConcrete,
+	SignedToAccountId32, TakeWeightCredit,
+};
+use xcm_executor::{
+	traits::{ConvertLocation, TransactAsset},
+	Assets, XcmExecutor,
+};
+
+pub type AccountId = TestAccount;
+pub type AssetId = u128;
+pub type Balance = u128;
+pub type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const PRECOMPILE_ADDRESS: H160 = H160::repeat_byte(0xCE);
+pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
+
+pub const SELF_PARA_ID: u32 = 2058;
+pub const SIBLING_PARA_ID: u32 = 1000;
+
+/// A simple account type.
+#[derive(
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Clone,
+	Encode,
+	Decode,
+	Debug,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+	derive_more::Display,
+	TypeInfo,
+)]
+pub enum TestAccount {
+	Alice,
+	Bob,
+	/// Sovereign account of the sibling parachains.
+	Sibling,
+	Bogus,
+}
+
+impl Default for TestAccount {
+	fn default() -> Self {
+		Self::Alice
+	}
+}
+
+impl AddressMapping<TestAccount> for TestAccount {
+	fn into_account_id(h160_account: H160) -> TestAccount {
+		match h160_account {
+			a if a == H160::repeat_byte(0xAA) => Self::Alice,
+			a if a == H160::repeat_byte(0xBB) => Self::Bob,
+			_ => Self::Bogus,
+		}
+	}
+}
+
+impl From<H160> for TestAccount {
+	fn from(x: H160) -> TestAccount {
+		TestAccount::into_account_id(x)
+	}
+}
+
+impl From<TestAccount> for H160 {
+	fn from(value: TestAccount) -> H160 {
+		match value {
+			TestAccount::Alice => H160::repeat_byte(0xAA),
+			TestAccount::Bob => H160::repeat_byte(0xBB),
+			TestAccount::Sibling => H160::repeat_byte(0x5B),
+			TestAccount::Bogus => H160::repeat_byte(0xDD),
+		}
+	}
+}
+
+impl From<TestAccount> for [u8; 32] {
+	fn from(value: TestAccount) -> [u8; 32] {
+		match value {
+			TestAccount::Alice => [0xAA; 32],
+			TestAccount::Bob => [0xBB; 32],
+			TestAccount::Sibling => [0x5B; 32],
+			TestAccount::Bogus => [0xDD; 32],
+		}
+	}
+}
+
+impl From<[u8; 32]> for TestAccount {
+	fn from(value: [u8; 32]) -> TestAccount {
+		match value {
+			a if a == [0xAA; 32] => Self::Alice,
+			a if a == [0xBB; 32] => Self::Bob,
+			a if a == [0x5B; 32] => Self::Sibling,
+			_ => Self::Bogus,
+		}
+	}
+}
+
+impl AddressToAssetId<AssetId> for Test {
+	fn address_to_asset_id(address: H160) -> Option<AssetId> {
+		let address_bytes: [u8; 20] = address.into();
+		if ASSET_PRECOMPILE_ADDRESS_PREFIX.eq(&address_bytes[0..4]) {
+			let mut data = [0u8; 16];
+			data.copy_from_slice(&address_bytes[4..20]);
+			Some(u128::from_be_bytes(data))
+		} else {
+			None
+		}
+	}
+
+	fn asset_id_to_address(asset_id: AssetId) -> H160 {
+		let mut data = [0u8; 20];
+		data[0..4].copy_from_slice(ASSET_PRECOMPILE_ADDRESS_PREFIX);
+		data[4..20].copy_from_slice(&asset_id.to_be_bytes());
+		H160::from(data)
+	}
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 19;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(Weight::MAX);
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u32;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const PrecompilesValue: TestPrecompileSet<Test> =
+	TestPrecompileSet(PhantomData);
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type PrecompilesType = TestPrecompileSet<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_xc_asset_config::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type XcAssetChanged = ();
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(RelayNetwork::get()), Parachain(SELF_PARA_ID));
+	pub SelfReserve: Junctions = Here;
+	pub SelfLocation: MultiLocation = Here.into();
+	pub UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+}
+
+/// Converts local 32 byte accounts to themselves and sibling parachains to `TestAccount::Sibling`.
+pub struct LocationToAccountId;
+impl ConvertLocation<AccountId> for LocationToAccountId {
+	fn convert_location(location: &MultiLocation) -> Option<AccountId> {
+		match location {
+			MultiLocation { parents: 0, interior: X1(AccountId32 { id, .. }) } => {
+				Some((*id).into())
+			},
+			MultiLocation { parents: 1, interior: X1(Parachain(_)) } => Some(TestAccount::Sibling),
+			_ => None,
+		}
+	}
+}
+
+pub type CurrencyTransactor =
+	CurrencyAdapter<Balances, IsConcrete<SelfLocation>, LocationToAccountId, AccountId, ()>;
+
+/// Withdraws any foreign asset without keeping track of balances.
+pub struct ForeignAssetsTransactor;
+impl TransactAsset for ForeignAssetsTransactor {
+	fn withdraw_asset(
+		what: &MultiAsset,
+		_who: &MultiLocation,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		match what.id {
+			Concrete(location) if location.parents > 0 => Ok(what.clone().into()),
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+}
+
+/// Records sent messages in `SentXcm`.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let msg = msg.take().ok_or(SendError::MissingArgument)?;
+		Ok(((dest, msg), MultiAssets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+		SentXcm::mutate(|sent| sent.push(ticket));
+		Ok(hash)
+	}
+}
+
+pub type XcmWeigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = TestSendXcm;
+	type AssetTransactor = (CurrencyTransactor, ForeignAssetsTransactor);
+	type OriginConverter = ();
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = DenyThenTry<DenyReserveDepositToRelayChain, TakeWeightCredit>;
+	type Weigher = XcmWeigher;
+	type Trader = ();
+	type ResponseHandler = ();
+	type AssetTrap = ();
+	type AssetClaims = ();
+	type SubscriptionService = ();
+	type PalletInstancesInfo = ();
+	type MaxAssetsIntoHolding = ConstU32<64>;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, ()>;
+	type XcmRouter = TestSendXcm;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = (
+		AllowOnlySendToReservePerAsset<
+			SelfReserve,
+			AssetId,
+			XcAssetConfig,
+			AccountBeneficiaries,
+			ConstU32<1>,
+		>,
+		AllowOnlySendNativeToSiblings<UniversalLocation, AccountBeneficiaries>,
+	);
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Nothing;
+	type Weigher = XcmWeigher;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type UniversalLocation = UniversalLocation;
+
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type MaxLockers = ConstU32<8>;
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TestPrecompileSet<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for TestPrecompileSet<R>
+where
+	WatrXcmPrecompile<R>: Precompile,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		match handle.code_address() {
+			a if a == PRECOMPILE_ADDRESS => Some(WatrXcmPrecompile::<R>::execute(handle)),
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer { is_precompile: address == PRECOMPILE_ADDRESS, extra_cost: 0 }
+	}
+}
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Evm: pallet_evm,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		XcAssetConfig: pallet_xc_asset_config,
+		PolkadotXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin},
+	}
+);
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = <frame_system::GenesisConfig<Test> as BuildStorage>::build_storage(
+		&frame_system::GenesisConfig::default(),
+	)
+	.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(TestAccount::Alice, 1_000), (TestAccount::Bob, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,262 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+use precompile_utils::testing::PrecompileTesterExt;
+use sp_std::vec;
+use xcm::VersionedMultiLocation;
+
+use super::*;
+use crate::mock::*;
+
+const SIBLING_ASSET_ID: AssetId = 1;
+const RELAY_ASSET_ID: AssetId = 2;
+
+fn precompiles() -> TestPrecompileSet<Test> {
+	PrecompilesValue::get()
+}
+
+fn sibling_asset_location() -> MultiLocation {
+	MultiLocation::new(1, X3(Parachain(SIBLING_PARA_ID), PalletInstance(50), GeneralIndex(1984)))
+}
+
+fn register_asset(asset_id: AssetId, location: MultiLocation) {
+	assert!(XcAssetConfig::register_asset_location(
+		RuntimeOrigin::root(),
+		Box::new(VersionedMultiLocation::V3(location)),
+		asset_id,
+	)
+	.is_ok());
+}
+
+fn beneficiary_32() -> MultiLocation {
+	AccountId32 { network: None, id: [0x11; 32] }.into()
+}
+
+fn beneficiary_20() -> MultiLocation {
+	AccountKey20 { network: None, key: [0x22; 20] }.into()
+}
+
+#[test]
+fn transfer_native_works() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferNative)
+					.write(2000u32)
+					.write(U256::from(100))
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(Balances::free_balance(TestAccount::Alice), 900);
+		assert_eq!(Balances::free_balance(TestAccount::Sibling), 100);
+
+		let native_on_sibling = MultiLocation::new(1, X1(Parachain(SELF_PARA_ID)));
+		assert_eq!(
+			SentXcm::get(),
+			vec![(
+				MultiLocation::new(1, X1(Parachain(2000))),
+				Xcm(vec![
+					ReserveAssetDeposited((native_on_sibling, 100).into()),
+					ClearOrigin,
+					BuyExecution { fees: (native_on_sibling, 100).into(), weight_limit: Unlimited },
+					DepositAsset { assets: Wild(All), beneficiary: beneficiary_32() },
+				])
+			)]
+		);
+	});
+}
+
+#[test]
+fn transfer_native_to_evm_works() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Bob,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferNativeToEvm)
+					.write(2000u32)
+					.write(U256::from(10))
+					.write(Address(H160::repeat_byte(0x22)))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(Balances::free_balance(TestAccount::Bob), 990);
+		let sent = SentXcm::get();
+		assert_eq!(sent.len(), 1);
+		assert_eq!(
+			sent[0].1 .0.last(),
+			Some(&DepositAsset { assets: Wild(All), beneficiary: beneficiary_20() })
+		);
+	});
+}
+
+#[test]
+fn transfer_asset_works() {
+	new_test_ext().execute_with(|| {
+		register_asset(SIBLING_ASSET_ID, sibling_asset_location());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferAsset)
+					.write(Address(Test::asset_id_to_address(SIBLING_ASSET_ID)))
+					.write(U256::from(50))
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		let asset_on_reserve = MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1984)));
+		assert_eq!(
+			SentXcm::get(),
+			vec![(
+				MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+				Xcm(vec![
+					WithdrawAsset((asset_on_reserve, 50).into()),
+					ClearOrigin,
+					BuyExecution { fees: (asset_on_reserve, 50).into(), weight_limit: Unlimited },
+					DepositAsset { assets: Wild(All), beneficiary: beneficiary_32() },
+				])
+			)]
+		);
+	});
+}
+
+#[test]
+fn transfer_asset_to_relay_works() {
+	new_test_ext().execute_with(|| {
+		register_asset(RELAY_ASSET_ID, MultiLocation::parent());
+
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferAssetToEvm)
+					.write(Address(Test::asset_id_to_address(RELAY_ASSET_ID)))
+					.write(U256::from(50))
+					.write(Address(H160::repeat_byte(0x22)))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(
+			SentXcm::get(),
+			vec![(
+				MultiLocation::parent(),
+				Xcm(vec![
+					WithdrawAsset((Here, 50).into()),
+					ClearOrigin,
+					BuyExecution { fees: (Here, 50).into(), weight_limit: Unlimited },
+					DepositAsset { assets: Wild(All), beneficiary: beneficiary_20() },
+				])
+			)]
+		);
+	});
+}
+
+#[test]
+fn transfer_asset_reverts_for_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferAsset)
+					.write(Address(H160::repeat_byte(0x11)))
+					.write(U256::from(50))
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Unknown asset");
+				true
+			});
+	});
+}
+
+#[test]
+fn transfer_asset_reverts_for_local_asset() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferAsset)
+					.write(Address(Test::asset_id_to_address(SIBLING_ASSET_ID)))
+					.write(U256::from(50))
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Asset is not cross-chain");
+				true
+			});
+		assert!(SentXcm::get().is_empty());
+	});
+}
+
+#[test]
+fn transfer_reverts_for_too_large_amount() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferNative)
+					.write(2000u32)
+					.write(U256::MAX)
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "Amount is too large");
+				true
+			});
+		assert_eq!(Balances::free_balance(TestAccount::Alice), 1_000);
+	});
+}
+
+#[test]
+fn transfer_reverts_when_execution_is_incomplete() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				TestAccount::Alice,
+				PRECOMPILE_ADDRESS,
+				EvmDataWriter::new_with_selector(Action::TransferNative)
+					.write(2000u32)
+					.write(U256::from(2_000))
+					.write(H256([0x11; 32]))
+					.build(),
+			)
+			.execute_reverts(|err| {
+				let reason = sp_std::str::from_utf8(err).unwrap();
+				assert_eq!(reason, "XCM execution incomplete");
+				true
+			});
+		assert_eq!(Balances::free_balance(TestAccount::Alice), 1_000);
+		assert!(SentXcm::get().is_empty());
+	});
+}
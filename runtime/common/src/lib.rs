@@ -18,6 +18,7 @@
 use sp_runtime::Perbill;
 
 mod impl_on_charge_evm_transaction;
+pub mod migrations;
 pub mod xcm_config;
 
 use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
@@ -0,0 +1,327 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations shared by the Watr runtimes.
+//!
+//! The one-off migrations are wrapped in [`RunOnce`], so they are skipped by the upgrades
+//! following the one that applied them. They must still be removed from the migrations of the
+//! runtimes once released.
+
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	migrations::RemovePallet,
+	parameter_types,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::{RuntimeDbWeight, Weight},
+	Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_motion::types::{DispatchAs, MajorityLevel, MotionCall};
+use parity_scale_codec::Encode;
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{
+	ensure,
+	storage::{with_transaction, TransactionOutcome},
+};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::Decode;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::{DispatchError, TryRuntimeError};
+
+/// Names of the one-off migrations already applied, see [`RunOnce`].
+#[frame_support::storage_alias]
+pub type AppliedMigrations = StorageMap<WatrMigrations, Twox64Concat, Vec<u8>, ()>;
+
+/// Applies the migration `Inner` on the first runtime upgrade that includes it only, recording
+/// it under `Name` in [`AppliedMigrations`].
+pub struct RunOnce<Name, Inner, DbWeight>(PhantomData<(Name, Inner, DbWeight)>);
+
+impl<Name, Inner, DbWeight> OnRuntimeUpgrade for RunOnce<Name, Inner, DbWeight>
+where
+	Name: Get<&'static str>,
+	Inner: OnRuntimeUpgrade,
+	DbWeight: Get<RuntimeDbWeight>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let name = Name::get().as_bytes().to_vec();
+		if AppliedMigrations::contains_key(&name) {
+			log::info!(target: "runtime::migrations", "{} was already applied", Name::get());
+			return DbWeight::get().reads(1);
+		}
+
+		AppliedMigrations::insert(&name, ());
+		Inner::on_runtime_upgrade().saturating_add(DbWeight::get().reads_writes(1, 1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		// The state of `Inner`, if it is applied by this upgrade
+		let state = if AppliedMigrations::contains_key(Name::get().as_bytes().to_vec()) {
+			None
+		} else {
+			Some(Inner::pre_upgrade()?)
+		};
+		Ok(state.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		ensure!(
+			AppliedMigrations::contains_key(Name::get().as_bytes().to_vec()),
+			"the migration was not recorded as applied"
+		);
+		match Option::<Vec<u8>>::decode(&mut &state[..]).map_err(|_| "invalid migration state")? {
+			Some(state) => Inner::post_upgrade(state),
+			None => Ok(()),
+		}
+	}
+}
+
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// Removes the storage of `pallet_sudo`, leaving the council, the collective instance `I` of the
+/// runtime `T`, as the only way to dispatch privileged calls.
+///
+/// Before the upgrade, checks the council has members to take over. After it, checks the council
+/// can still authorize a runtime upgrade through a super majority motion, see
+/// [`authorize_upgrade_through_council`].
+pub struct RemoveSudo<T, I>(PhantomData<(T, I)>);
+
+impl<T, I> OnRuntimeUpgrade for RemoveSudo<T, I>
+where
+	T: pallet_collective::Config<I>
+		+ pallet_motion::Config
+		+ cumulus_pallet_parachain_system::Config,
+	I: 'static,
+	<T as pallet_collective::Config<I>>::Proposal: From<pallet_motion::Call<T>>,
+	<T as pallet_motion::Config>::RuntimeCall: From<cumulus_pallet_parachain_system::Call<T>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		RemovePallet::<SudoPalletName, T::DbWeight>::on_runtime_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(
+			!pallet_collective::Pallet::<T, I>::members().is_empty(),
+			"the council must have members to replace sudo"
+		);
+		RemovePallet::<SudoPalletName, T::DbWeight>::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		RemovePallet::<SudoPalletName, T::DbWeight>::post_upgrade(state)?;
+
+		// The upgrade is only authorized to check the council can do it, and rolled back.
+		with_transaction(|| {
+			let result = authorize_upgrade_through_council::<T, I>(Default::default());
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+		})?
+		.map_err(Into::into)
+	}
+}
+
+/// Authorizes a runtime upgrade the way the council, the collective instance `I` of the runtime
+/// `T`, does without sudo: a council proposal for a super majority motion, approved by every
+/// member and executed once the motion delay is over.
+pub fn authorize_upgrade_through_council<T, I>(code_hash: T::Hash) -> Result<(), &'static str>
+where
+	T: pallet_collective::Config<I>
+		+ pallet_motion::Config
+		+ cumulus_pallet_parachain_system::Config,
+	I: 'static,
+	<T as pallet_collective::Config<I>>::Proposal: From<pallet_motion::Call<T>>,
+	<T as pallet_motion::Config>::RuntimeCall: From<cumulus_pallet_parachain_system::Call<T>>,
+{
+	let signed = |who: T::AccountId| -> <T as frame_system::Config>::RuntimeOrigin {
+		frame_system::RawOrigin::Signed(who).into()
+	};
+	let members = pallet_collective::Pallet::<T, I>::members();
+	let proposer = members.first().cloned().ok_or("the council has no members")?;
+	let threshold = members.len() as u32;
+
+	let upgrade: Box<<T as pallet_motion::Config>::RuntimeCall> = Box::new(
+		cumulus_pallet_parachain_system::Call::<T>::authorize_upgrade {
+			code_hash,
+			check_version: true,
+		}
+		.into(),
+	);
+	let motion: <T as pallet_collective::Config<I>>::Proposal =
+		pallet_motion::Call::<T>::super_majority { call: upgrade.clone() }.into();
+	let motion_hash = pallet_motion::Pallet::<T>::motion_hash(
+		&MotionCall::<T>::Single(upgrade.clone()),
+		MajorityLevel::SuperMajority,
+		DispatchAs::Root,
+	);
+
+	let proposal_hash = T::Hashing::hash_of(&motion);
+	let proposal_index = pallet_collective::Pallet::<T, I>::proposal_count();
+	let length_bound = motion.encoded_size() as u32;
+	let weight_bound = motion.get_dispatch_info().weight;
+	pallet_collective::Pallet::<T, I>::propose(
+		signed(proposer.clone()),
+		threshold,
+		Box::new(motion),
+		length_bound,
+	)
+	.map_err(|_| "the council could not propose the motion")?;
+	// A single member council executes its proposals right away
+	if threshold > 1 {
+		for member in members {
+			pallet_collective::Pallet::<T, I>::vote(
+				signed(member),
+				proposal_hash,
+				proposal_index,
+				true,
+			)
+			.map_err(|_| "a council member could not vote for the motion")?;
+		}
+		pallet_collective::Pallet::<T, I>::close(
+			signed(proposer.clone()),
+			proposal_hash,
+			proposal_index,
+			weight_bound,
+			length_bound,
+		)
+		.map_err(|_| "the council could not close the proposal")?;
+	}
+
+	if pallet_motion::Pallet::<T>::pending_motion(motion_hash).is_some() {
+		let ready_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number()
+			.saturating_add(pallet_motion::Pallet::<T>::delay(MajorityLevel::SuperMajority));
+		frame_system::Pallet::<T>::set_block_number(ready_at);
+		pallet_motion::Pallet::<T>::execute_ready(
+			signed(proposer),
+			motion_hash,
+			upgrade.get_dispatch_info().weight,
+		)
+		.map_err(|_| "the queued motion could not be executed")?;
+	}
+
+	match pallet_motion::Pallet::<T>::executed_motions().last() {
+		Some(motion) if motion.motion_hash == motion_hash && motion.result.is_ok() => Ok(()),
+		_ => Err("a council motion must be able to authorize a runtime upgrade"),
+	}
+}
+
+/// Registers the reserve location of the foreign assets in `Assets` with
+/// `pallet_xc_asset_config`, which the XCM config reads the mapping from. Assets that are
+/// already registered are left untouched.
+pub struct RegisterXcAssets<T, Assets>(PhantomData<(T, Assets)>);
+
+impl<T, Assets> OnRuntimeUpgrade for RegisterXcAssets<T, Assets>
+where
+	T: pallet_xc_asset_config::Config,
+	Assets: Get<Vec<(T::AssetId, MultiLocation)>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let assets = Assets::get();
+		let mut registered = 0u64;
+		for (asset_id, location) in &assets {
+			if pallet_xc_asset_config::Pallet::<T>::get_xc_asset_location(*asset_id).is_some() {
+				continue;
+			}
+			match pallet_xc_asset_config::Pallet::<T>::register_asset_location(
+				frame_system::RawOrigin::Root.into(),
+				Box::new(VersionedMultiLocation::V3(*location)),
+				*asset_id,
+			) {
+				Ok(_) => registered += 1,
+				Err(e) => log::warn!(
+					target: "runtime::migrations",
+					"could not register the location of asset {:?}: {:?}",
+					asset_id,
+					e
+				),
+			}
+		}
+
+		log::info!(target: "runtime::migrations", "registered {} foreign asset locations", registered);
+		T::DbWeight::get().reads_writes(2 * assets.len() as u64, 3 * registered)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		for (asset_id, location) in Assets::get() {
+			ensure!(
+				pallet_xc_asset_config::Pallet::<T>::get_xc_asset_location(asset_id).is_some(),
+				"foreign asset location was not registered"
+			);
+			ensure!(
+				pallet_xc_asset_config::Pallet::<T>::get_asset_id(location).is_some(),
+				"foreign asset location is registered for another asset"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Sets the execution fee rate of the foreign assets in `Rates` in `pallet_xc_asset_config`,
+/// which the XCM trader reads the rates from. Rates that are already set are left untouched.
+///
+/// The assets must be registered first, see [`RegisterXcAssets`].
+pub struct SetXcAssetUnitsPerSecond<T, Rates>(PhantomData<(T, Rates)>);
+
+impl<T, Rates> OnRuntimeUpgrade for SetXcAssetUnitsPerSecond<T, Rates>
+where
+	T: pallet_xc_asset_config::Config,
+	Rates: Get<Vec<(MultiLocation, u128)>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let rates = Rates::get();
+		let mut updated = 0u64;
+		for (location, units_per_second) in &rates {
+			if pallet_xc_asset_config::Pallet::<T>::get_units_per_second(*location).is_some() {
+				continue;
+			}
+			match pallet_xc_asset_config::Pallet::<T>::set_asset_units_per_second(
+				frame_system::RawOrigin::Root.into(),
+				Box::new(VersionedMultiLocation::V3(*location)),
+				*units_per_second,
+			) {
+				Ok(_) => updated += 1,
+				Err(e) => log::warn!(
+					target: "runtime::migrations",
+					"could not set the units per second of {:?}: {:?}",
+					location,
+					e
+				),
+			}
+		}
+
+		log::info!(target: "runtime::migrations", "set {} foreign asset fee rates", updated);
+		T::DbWeight::get().reads_writes(2 * rates.len() as u64, updated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		for (location, _) in Rates::get() {
+			ensure!(
+				pallet_xc_asset_config::Pallet::<T>::get_units_per_second(location).is_some(),
+				"foreign asset fee rate was not set"
+			);
+		}
+		Ok(())
+	}
+}
@@ -18,166 +18,425 @@ use core::marker::PhantomData;
 
 use frame_support::{
 	log,
-	traits::{Contains, ContainsPair, Get},
+	traits::{Contains, ContainsPair, Get, ProcessMessageError},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
+use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetLocation};
 use sp_runtime::traits::MaybeEquivalence;
+use sp_std::vec;
 use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{Properties, ShouldExecute, TakeRevenue, WeightTrader},
+	Assets,
+};
 
-pub struct AsForeignToLocal<Prefix, Asset, AssetId, ConvertAssetId>(
-	PhantomData<(Prefix, Asset, AssetId, ConvertAssetId)>,
-);
+/// Converts between the location of a foreign asset and its local asset id, using the
+/// reserve-location to local-asset-id pairs registered in `AssetMapper`, such as
+/// `pallet_xc_asset_config`.
+pub struct AsForeignToLocal<AssetId, AssetMapper>(PhantomData<(AssetId, AssetMapper)>);
 
-impl<
-		Prefix: Get<MultiLocation>,
-		Asset: Get<(u128, u128)>,
-		AssetId: Clone,
-		ConvertAssetId: MaybeEquivalence<u128, AssetId>,
-	> MaybeEquivalence<MultiLocation, AssetId>
-	for AsForeignToLocal<Prefix, Asset, AssetId, ConvertAssetId>
+impl<AssetId: Clone, AssetMapper: XcAssetLocation<AssetId>> MaybeEquivalence<MultiLocation, AssetId>
+	for AsForeignToLocal<AssetId, AssetMapper>
 {
 	fn convert(id: &MultiLocation) -> Option<AssetId> {
-		let prefix = Prefix::get();
-		let asset = Asset::get();
-
-		match id.match_and_split(&prefix) {
-			Some(&GeneralIndex(asset_id)) => {
-				if asset_id == asset.0 {
-					ConvertAssetId::convert(&asset.1)
-				} else {
-					return None;
-				}
-			},
-			_ => None,
-		}
+		AssetMapper::get_asset_id(*id)
 	}
 
 	fn convert_back(what: &AssetId) -> Option<MultiLocation> {
-		let mut location = Prefix::get();
-		let id = ConvertAssetId::convert_back(what)?;
-		let asset = Asset::get();
-
-		if id == asset.1 {
-			log::trace!(
-				target: "xcm::execute_xcm_in_credit",
-				"location: {:?}",
-				location
-			);
-			location.push_interior(Junction::GeneralIndex(asset.0)).ok()?;
-		} else {
-			return None;
-		}
+		let location = AssetMapper::get_xc_asset_location(what.clone());
+		log::trace!(
+			target: "xcm::execute_xcm_in_credit",
+			"location: {:?}",
+			location
+		);
+		location
+	}
+}
 
-		Some(location)
+/// Location of the chain holding the reserve of the foreign asset at `location`: the first
+/// parachain of its interior, or the relay chain if there is none.
+///
+/// Local assets, which are not reserved anywhere else, have no reserve location.
+pub fn reserve_location(location: &MultiLocation) -> Option<MultiLocation> {
+	if location.parents == 0 {
+		return None;
+	}
+	match location.first_interior() {
+		Some(Parachain(id)) => Some(MultiLocation::new(location.parents, X1(Parachain(*id)))),
+		_ => Some(MultiLocation::new(location.parents, Here)),
 	}
 }
 
-/// Accepts an asset if it is a native asset from a particular `MultiLocation`.
-pub struct ConcreteNativeAssetFrom<Location>(PhantomData<Location>);
-impl<Location: Get<MultiLocation>> ContainsPair<MultiAsset, MultiLocation>
-	for ConcreteNativeAssetFrom<Location>
+/// Accepts a foreign asset registered in `AssetMapper` if it comes from its reserve location.
+pub struct ConcreteNativeAssetFrom<AssetId, AssetMapper>(PhantomData<(AssetId, AssetMapper)>);
+impl<AssetId, AssetMapper: XcAssetLocation<AssetId>> ContainsPair<MultiAsset, MultiLocation>
+	for ConcreteNativeAssetFrom<AssetId, AssetMapper>
 {
 	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
-		let prefix = Location::get();
-		log::trace!(target: "xcm::filter_asset_location", "prefix: {:?}, origin: {:?}", prefix, origin);
-		&prefix == origin
-			&& match asset {
-				MultiAsset { id: xcm::latest::AssetId::Concrete(asset_loc), fun: Fungible(_a) } => {
-					asset_loc.starts_with(&prefix)
-				},
-				_ => false,
-			}
+		log::trace!(target: "xcm::filter_asset_location", "asset: {:?}, origin: {:?}", asset, origin);
+		match asset {
+			MultiAsset { id: Concrete(asset_loc), fun: Fungible(_a) } => {
+				reserve_location(asset_loc).as_ref() == Some(origin)
+					&& AssetMapper::get_asset_id(*asset_loc).is_some()
+			},
+			_ => false,
+		}
 	}
 }
 
-pub struct AllowOnlySendToReservePerAsset<SelfLocation, ReserveAssetPallet, Assets>(
-	PhantomData<(SelfLocation, ReserveAssetPallet, Assets)>,
-);
+/// Only allows local XCM executions sending foreign assets registered in `AssetMapper` back to
+/// their reserve location.
+///
+/// The message must be `[WithdrawAsset, ClearOrigin?, InitiateReserveWithdraw, SetTopic?]`,
+/// withdrawing up to `MaxAssets` assets with the same reserve and sending all of them to it. The
+/// message for the reserve must be `[ClearOrigin?, BuyExecution, DepositAsset, SetTopic?]`,
+/// paying fees with any of the withdrawn assets and depositing all of them to a location matching
+/// `Beneficiaries`.
+pub struct AllowOnlySendToReservePerAsset<
+	SelfLocation,
+	AssetId,
+	AssetMapper,
+	Beneficiaries,
+	MaxAssets,
+>(PhantomData<(SelfLocation, AssetId, AssetMapper, Beneficiaries, MaxAssets)>);
 impl<
 		SelfLocation: Get<Junctions>,
-		ReserveAssetPallet: Get<MultiLocation>,
-		Assets: Get<(u128, u128)>,
+		AssetId,
+		AssetMapper: XcAssetLocation<AssetId>,
+		Beneficiaries: Contains<MultiLocation>,
+		MaxAssets: Get<u32>,
 		RuntimeCall,
 	> Contains<(MultiLocation, Xcm<RuntimeCall>)>
-	for AllowOnlySendToReservePerAsset<SelfLocation, ReserveAssetPallet, Assets>
+	for AllowOnlySendToReservePerAsset<SelfLocation, AssetId, AssetMapper, Beneficiaries, MaxAssets>
 {
 	fn contains(t: &(MultiLocation, Xcm<RuntimeCall>)) -> bool {
-		let message = &t.1;
-		let assets = Assets::get();
-		let reserve_asset_id = assets.0;
-
-		let mut reserve_pallet_location = ReserveAssetPallet::get();
-		let mut reserve_location = reserve_pallet_location.clone();
-		reserve_location.take_last();
+		let Xcm(message) = &t.1;
+		let (assets, instructions) = match without_topic(message) {
+			[WithdrawAsset(assets), instructions @ ..] => {
+				(assets.inner(), without_clear_origin(instructions))
+			},
+			_ => return false,
+		};
+		let (sent_assets, reserve, Xcm(reserve_message)) = match instructions {
+			[InitiateReserveWithdraw { assets, reserve, xcm }] => (assets, reserve, xcm),
+			_ => return false,
+		};
+		if assets.is_empty()
+			|| assets.len() > MaxAssets::get() as usize
+			|| !matches_all(sent_assets, assets.len())
+		{
+			return false;
+		}
 
-		if reserve_pallet_location.append_with(X1(GeneralIndex(reserve_asset_id))).is_err() {
+		// Every withdrawn asset must be registered and have the reserve the message is sent to
+		let assets_are_correct = assets.iter().all(|asset| match asset {
+			MultiAsset { id: Concrete(location), fun: Fungible(_) } => {
+				AssetMapper::get_asset_id(*location).is_some()
+					&& reserve_location(location).as_ref() == Some(reserve)
+			},
+			_ => false,
+		});
+		if !assets_are_correct {
 			return false;
+		}
+
+		let fees = match deposit_fees::<_, Beneficiaries>(reserve_message, assets.len()) {
+			Some(fees) => fees,
+			None => return false,
+		};
+		// Fees are paid with one of the withdrawn assets, as seen from the reserve
+		assets.iter().any(|asset| match (asset, fees) {
+			(
+				MultiAsset { id: Concrete(location), fun: Fungible(amount) },
+				MultiAsset { id: Concrete(fees_location), fun: Fungible(buy_amount) },
+			) => {
+				location
+					.reanchored(reserve, SelfLocation::get())
+					.map_or(false, |location| &location == fees_location)
+					&& amount >= buy_amount
+			},
+			_ => false,
+		})
+	}
+}
+
+/// Only allows local XCM executions sending the native asset to a sibling parachain, which holds
+/// it in the sovereign account of this chain.
+///
+/// The message must be `[WithdrawAsset, ClearOrigin?, DepositReserveAsset, SetTopic?]`, with the
+/// same message for the sibling as `AllowOnlySendToReservePerAsset` expects for the reserve.
+pub struct AllowOnlySendNativeToSiblings<UniversalLocation, Beneficiaries>(
+	PhantomData<(UniversalLocation, Beneficiaries)>,
+);
+impl<
+		UniversalLocation: Get<InteriorMultiLocation>,
+		Beneficiaries: Contains<MultiLocation>,
+		RuntimeCall,
+	> Contains<(MultiLocation, Xcm<RuntimeCall>)>
+	for AllowOnlySendNativeToSiblings<UniversalLocation, Beneficiaries>
+{
+	fn contains(t: &(MultiLocation, Xcm<RuntimeCall>)) -> bool {
+		let Xcm(message) = &t.1;
+		let (assets, instructions) = match without_topic(message) {
+			[WithdrawAsset(assets), instructions @ ..] => {
+				(assets, without_clear_origin(instructions))
+			},
+			_ => return false,
 		};
-		let reserve_asset_location = reserve_pallet_location.clone();
-		let self_location = SelfLocation::get();
-		if reserve_pallet_location.reanchor(&reserve_location, self_location).is_err() {
+		let (dest, Xcm(inner_xcm)) = match instructions {
+			[DepositReserveAsset { assets: Wild(All), dest, xcm }] => (dest, xcm),
+			_ => return false,
+		};
+		let withdraw_amount = match assets.inner().as_slice() {
+			[MultiAsset {
+				id: Concrete(MultiLocation { parents: 0, interior: Here }),
+				fun: Fungible(amount),
+			}] => *amount,
+			_ => return false,
+		};
+		if !matches!(dest, MultiLocation { parents: 1, interior: X1(Parachain(_)) }) {
 			return false;
+		}
+
+		// The native asset as seen from the sibling
+		let native_location = match MultiLocation::here().reanchored(dest, UniversalLocation::get())
+		{
+			Ok(location) => location,
+			Err(_) => return false,
 		};
-		let reserve_asset_location_as_local = reserve_pallet_location.clone();
-		let mut withdraw_amount = 0;
-		let mut buy_amount = 1;
-
-		let Xcm(inner_message) = message;
-
-		if inner_message.len() == 2 {
-			let withdraw_is_correct = match &inner_message[0] {
-				WithdrawAsset(assets) => {
-					let asset_is_correct = if let Some(asset) = assets.get(0) {
-						match asset {
-							MultiAsset { id: Concrete(reserve_asset), fun: Fungible(amount) } => {
-								withdraw_amount = *amount;
-								reserve_asset == &reserve_asset_location
-							},
-							_ => false,
-						}
-					} else {
-						false
-					};
-					asset_is_correct && assets.len() == 1
-				},
-				_ => false,
-			};
-
-			let initiate_reserve_withdraw_is_correct = match &inner_message[1] {
-				InitiateReserveWithdraw { assets: Wild(all), reserve, xcm: Xcm(inner_xcm) } => {
-					if inner_xcm.len() == 2 && all == &All && reserve == &reserve_location {
-						let buy_execution_is_correct = match &inner_xcm[0] {
-							BuyExecution {
-								fees:
-									MultiAsset { id: Concrete(reserve_asset), fun: Fungible(amount) },
-								weight_limit: Unlimited,
-							} => {
-								buy_amount = *amount;
-								reserve_asset == &reserve_asset_location_as_local
-							},
-							_ => false,
-						};
-						let deposit_asset_is_correct = matches!(
-							&inner_xcm[1],
-							DepositAsset {
-								assets: Wild(All),
-								beneficiary: MultiLocation {
-									parents: 0,
-									interior: X1(AccountId32 { .. })
-								}
-							}
-						);
-						buy_execution_is_correct && deposit_asset_is_correct
-					} else {
-						false
+		match deposit_fees::<_, Beneficiaries>(inner_xcm, 1) {
+			Some(MultiAsset { id: Concrete(fees_location), fun: Fungible(buy_amount) }) => {
+				fees_location == &native_location && withdraw_amount >= *buy_amount
+			},
+			_ => false,
+		}
+	}
+}
+
+/// Beneficiaries that are a 32 byte or an Ethereum account on the destination chain.
+pub struct AccountBeneficiaries;
+impl Contains<MultiLocation> for AccountBeneficiaries {
+	fn contains(beneficiary: &MultiLocation) -> bool {
+		matches!(
+			beneficiary,
+			MultiLocation { parents: 0, interior: X1(AccountId32 { .. } | AccountKey20 { .. }) }
+		)
+	}
+}
+
+/// Returns the fees of `message` if it is `[ClearOrigin?, BuyExecution, DepositAsset, SetTopic?]`,
+/// depositing all of `asset_count` assets to a location matching `Beneficiaries`.
+fn deposit_fees<RuntimeCall, Beneficiaries: Contains<MultiLocation>>(
+	message: &[Instruction<RuntimeCall>],
+	asset_count: usize,
+) -> Option<&MultiAsset> {
+	match without_clear_origin(without_topic(message)) {
+		[BuyExecution { fees, weight_limit: Unlimited }, DepositAsset { assets, beneficiary }]
+			if matches_all(assets, asset_count) && Beneficiaries::contains(beneficiary) =>
+		{
+			Some(fees)
+		},
+		_ => None,
+	}
+}
+
+/// Strips the `SetTopic` the message may end with.
+fn without_topic<RuntimeCall>(message: &[Instruction<RuntimeCall>]) -> &[Instruction<RuntimeCall>] {
+	match message {
+		[instructions @ .., SetTopic(_)] => instructions,
+		_ => message,
+	}
+}
+
+/// Strips the `ClearOrigin` the message may start with.
+fn without_clear_origin<RuntimeCall>(
+	message: &[Instruction<RuntimeCall>],
+) -> &[Instruction<RuntimeCall>] {
+	match message {
+		[ClearOrigin, instructions @ ..] => instructions,
+		_ => message,
+	}
+}
+
+/// Returns true if `filter` matches all of `count` different assets.
+fn matches_all(filter: &MultiAssetFilter, count: usize) -> bool {
+	match filter {
+		Wild(All) => true,
+		Wild(AllCounted(limit)) => *limit as usize >= count,
+		_ => false,
+	}
+}
+
+/// Denies reserve transfers to the relay chain, which does not accept this chain as a reserve.
+///
+/// Unlike `DenyReserveTransferToRelayChain`, assets whose reserve is the relay chain can still be
+/// withdrawn back to it with `InitiateReserveWithdraw`.
+pub struct DenyReserveDepositToRelayChain;
+impl ShouldExecute for DenyReserveDepositToRelayChain {
+	fn should_execute<RuntimeCall>(
+		_origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let deposits_to_relay = instructions.iter().any(|instruction| {
+			matches!(
+				instruction,
+				DepositReserveAsset { dest: MultiLocation { parents: 1, interior: Here }, .. }
+					| TransferReserveAsset {
+						dest: MultiLocation { parents: 1, interior: Here },
+						..
 					}
-				},
-				_ => false,
-			};
+			)
+		});
+		if deposits_to_relay {
+			return Err(ProcessMessageError::Unsupported);
+		}
+		Ok(())
+	}
+}
+
+/// Message sending `amount` of the foreign asset at `asset_location` back to its reserve, for
+/// `beneficiary`, in the shape allowed by `AllowOnlySendToReservePerAsset`.
+///
+/// The whole amount is available to pay for the execution on the reserve.
+pub fn send_to_reserve_message<RuntimeCall>(
+	asset_location: MultiLocation,
+	amount: u128,
+	beneficiary: Junction,
+	universal_location: InteriorMultiLocation,
+) -> Option<Xcm<RuntimeCall>> {
+	let reserve = reserve_location(&asset_location)?;
+	let fees_location = asset_location.reanchored(&reserve, universal_location).ok()?;
+	Some(Xcm(vec![
+		WithdrawAsset((asset_location, amount).into()),
+		InitiateReserveWithdraw {
+			assets: Wild(All),
+			reserve,
+			xcm: Xcm(vec![
+				BuyExecution { fees: (fees_location, amount).into(), weight_limit: Unlimited },
+				DepositAsset { assets: Wild(All), beneficiary: beneficiary.into() },
+			]),
+		},
+	]))
+}
+
+/// Message sending `amount` of the native asset to the sibling parachain `para_id`, for
+/// `beneficiary`, in the shape allowed by `AllowOnlySendNativeToSiblings`.
+///
+/// The whole amount is available to pay for the execution on the sibling.
+pub fn send_native_to_sibling_message<RuntimeCall>(
+	para_id: u32,
+	amount: u128,
+	beneficiary: Junction,
+	universal_location: InteriorMultiLocation,
+) -> Option<Xcm<RuntimeCall>> {
+	let dest = MultiLocation::new(1, X1(Parachain(para_id)));
+	let fees_location = MultiLocation::here().reanchored(&dest, universal_location).ok()?;
+	Some(Xcm(vec![
+		WithdrawAsset((Here, amount).into()),
+		DepositReserveAsset {
+			assets: Wild(All),
+			dest,
+			xcm: Xcm(vec![
+				BuyExecution { fees: (fees_location, amount).into(), weight_limit: Unlimited },
+				DepositAsset { assets: Wild(All), beneficiary: beneficiary.into() },
+			]),
+		},
+	]))
+}
 
-			return withdraw_is_correct
-				&& initiate_reserve_withdraw_is_correct
-				&& (withdraw_amount >= buy_amount);
+/// Buys execution with a foreign asset, at the units per second `FixedRate` returns for the
+/// location of the asset, such as the rates governance sets in `pallet_xc_asset_config`.
+///
+/// Unused weight is refunded in the same asset. The fees are passed to `Revenue` when the trader
+/// is dropped.
+pub struct FixedRateOfForeignAsset<FixedRate, Revenue: TakeRevenue> {
+	weight: Weight,
+	consumed: u128,
+	/// Location of the asset paying for the execution, and its units per second
+	asset_rate: Option<(MultiLocation, u128)>,
+	_phantom: PhantomData<(FixedRate, Revenue)>,
+}
+
+impl<FixedRate, Revenue: TakeRevenue> FixedRateOfForeignAsset<FixedRate, Revenue> {
+	fn fee(weight: Weight, units_per_second: u128) -> u128 {
+		units_per_second.saturating_mul(weight.ref_time() as u128)
+			/ (WEIGHT_REF_TIME_PER_SECOND as u128)
+	}
+}
+
+impl<FixedRate: ExecutionPaymentRate, Revenue: TakeRevenue> WeightTrader
+	for FixedRateOfForeignAsset<FixedRate, Revenue>
+{
+	fn new() -> Self {
+		Self { weight: Weight::zero(), consumed: 0, asset_rate: None, _phantom: PhantomData }
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: Assets,
+		_context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		log::trace!(
+			target: "xcm::weight",
+			"FixedRateOfForeignAsset::buy_weight weight: {:?}, payment: {:?}",
+			weight,
+			payment
+		);
+		let asset_location = match payment.fungible_assets_iter().next() {
+			Some(MultiAsset { id: Concrete(asset_location), .. }) => asset_location,
+			_ => return Err(XcmError::TooExpensive),
+		};
+		// All the execution of a message is paid with the same asset
+		let units_per_second = match self.asset_rate {
+			Some((location, units_per_second)) if location == asset_location => units_per_second,
+			Some(_) => return Err(XcmError::NotWithdrawable),
+			None => {
+				FixedRate::get_units_per_second(asset_location).ok_or(XcmError::TooExpensive)?
+			},
+		};
+
+		let amount = Self::fee(weight, units_per_second);
+		if amount == 0 {
+			return Ok(payment);
+		}
+		let unused = payment
+			.checked_sub((asset_location, amount).into())
+			.map_err(|_| XcmError::TooExpensive)?;
+
+		self.weight = self.weight.saturating_add(weight);
+		self.consumed = self.consumed.saturating_add(amount);
+		self.asset_rate = Some((asset_location, units_per_second));
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<MultiAsset> {
+		log::trace!(
+			target: "xcm::weight",
+			"FixedRateOfForeignAsset::refund_weight weight: {:?}",
+			weight
+		);
+		let (asset_location, units_per_second) = self.asset_rate?;
+		let weight = weight.min(self.weight);
+		let amount = Self::fee(weight, units_per_second).min(self.consumed);
+		self.weight = self.weight.saturating_sub(weight);
+		self.consumed = self.consumed.saturating_sub(amount);
+		if amount > 0 {
+			Some((asset_location, amount).into())
+		} else {
+			None
+		}
+	}
+}
+
+impl<FixedRate, Revenue: TakeRevenue> Drop for FixedRateOfForeignAsset<FixedRate, Revenue> {
+	fn drop(&mut self) {
+		if let Some((asset_location, _)) = self.asset_rate {
+			if self.consumed > 0 {
+				Revenue::take_revenue((asset_location, self.consumed).into());
+			}
 		}
-		false
 	}
 }
@@ -35,6 +35,10 @@
 // --json
 // --header=./file_header.txt
 // --output=./runtime/devnet/src/weights/pallet_did.rs
+//
+// The `DidLocations` writes of `create_did` and `remove_did`, and `add_credential_querier`,
+// `remove_credential_querier` and `query_credential`, were added to `pallet_did` after the run
+// above. The calls use the `pallet_did` weights until this file is regenerated.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -53,6 +57,8 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `DID::Issuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `DID::Services` (r:10 w:10)
 	/// Proof: `DID::Services` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `DID::DidLocations` (r:0 w:1)
+	/// Proof: `DID::DidLocations` (`max_values`: None, `max_size`: Some(578), added: 3053, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn create_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -65,7 +71,7 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(6_325_673, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -87,6 +93,8 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `DID::Services` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
 	/// Storage: `DID::Issuers` (r:1 w:0)
 	/// Proof: `DID::Issuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `DID::DidLocations` (r:0 w:1)
+	/// Proof: `DID::DidLocations` (`max_values`: None, `max_size`: Some(578), added: 3053, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn remove_did(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -99,7 +107,7 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(6_475_267, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(m.into()))
 	}
@@ -253,4 +261,13 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn add_credential_querier() -> Weight {
+		<pallet_did::weights::SubstrateWeight<T> as pallet_did::WeightInfo>::add_credential_querier()
+	}
+	fn remove_credential_querier() -> Weight {
+		<pallet_did::weights::SubstrateWeight<T> as pallet_did::WeightInfo>::remove_credential_querier()
+	}
+	fn query_credential() -> Weight {
+		<pallet_did::weights::SubstrateWeight<T> as pallet_did::WeightInfo>::query_credential()
+	}
 }
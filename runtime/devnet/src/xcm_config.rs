@@ -20,26 +20,27 @@
 
 use super::{
 	weights, weights::ExtrinsicBaseWeight, AccountId, AllPalletsWithSystem, AssetId, Assets,
-	Authorship, Balance, Balances, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue, KILOWEI,
+	Authorship, Balance, Balances, MoreThanHalfTechnicalCommittee, ParachainInfo, ParachainSystem,
+	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, TrappedAssets, WeightToFee,
+	XcAssetConfig, XcmpQueue, KILOWEI,
 };
 use frame_support::{
 	match_types, parameter_types,
-	traits::{ConstU32, Everything, Nothing, PalletInfoAccess},
-	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+	traits::{ConstU32, Contains, EitherOfDiverse, Everything, Nothing, PalletInfoAccess},
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 };
 
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
-use xcm::{latest::prelude::*, prelude::AssetId as XcmAssetId};
+use xcm::latest::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, AsPrefixedGeneralIndex, ConvertedConcreteId, CurrencyAdapter,
-	DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedRateOfFungible,
-	FixedWeightBounds, FungiblesAdapter, IsConcrete, NativeAsset, NoChecking, ParentIsPreset,
-	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-	UsingComponents,
+	DenyThenTry, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FungiblesAdapter,
+	HashedDescription, IsConcrete, NativeAsset, NoChecking, ParentIsPreset, RelayChainAsNative,
+	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, UsingComponents,
+	WeightInfoBounds,
 };
 use xcm_executor::{traits::JustTry, XcmExecutor};
 
@@ -47,29 +48,27 @@ use cumulus_primitives_utility::{ParentAsUmp, XcmFeesTo32ByteAccount};
 
 use watr_common::{
 	impls::DealWithFees,
-	xcm_config::{AllowOnlySendToReservePerAsset, AsForeignToLocal, ConcreteNativeAssetFrom},
+	xcm_config::{
+		AccountBeneficiaries, AllowOnlySendNativeToSiblings, AllowOnlySendToReservePerAsset,
+		AsForeignToLocal, ConcreteNativeAssetFrom, DenyReserveDepositToRelayChain,
+		FixedRateOfForeignAsset,
+	},
 };
 
 use frame_system::EnsureRoot;
 
 parameter_types! {
-	pub const FUSD: (u128, u128) = (1984, 1984); // (Reserve AssetId, Local AssetId)
 	pub const RelayLocation: MultiLocation = MultiLocation::parent();
 	// pub SelfReserve: MultiLocation = MultiLocation { parents:0, interior: Here };
 	pub SelfReserve: Junctions = Junctions::Here;
-	pub StatemintLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(1000)));
-	pub StatemintAssetsPalletLocation: MultiLocation =
-		MultiLocation::new(1, X2(Parachain(1000), PalletInstance(50)));
 	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
 	pub SelfAssetsPalletLocation: MultiLocation = PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
 	pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
 	pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
-	pub FUSDperSecond: (XcmAssetId, u128, u128) = (
-		MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(FUSD::get().1))).into(),
-		default_fee_per_second() * 10,
-		0
-	);
+	/// Foreign USD, asset 1984 of the Asset Hub assets pallet.
+	pub FUSDLocation: MultiLocation =
+		MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(1984)));
 	pub UniversalLocation: InteriorMultiLocation =
 		X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
 	pub XcmAssetFeesReceiver: Option<AccountId> = Authorship::author();
@@ -96,6 +95,9 @@ pub type LocationToAccountId = (
 	SiblingParachainConvertsVia<Sibling, AccountId>,
 	// Straight up local `AccountId32` origins just alias directly to `AccountId`.
 	AccountId32Aliases<RelayNetwork, AccountId>,
+	// Other locations, such as accounts on sibling chains, convert to the hash of their
+	// description.
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
 /// Means for transacting assets on this chain.
@@ -112,25 +114,28 @@ pub type CurrencyTransactor = CurrencyAdapter<
 	(),
 >;
 
+/// Matches the fungible assets of `pallet_assets`, by their local or foreign location.
+pub type FungiblesMatcher = (
+	ConvertedConcreteId<
+		AssetId,
+		Balance,
+		AsPrefixedGeneralIndex<SelfAssetsPalletLocation, AssetId, JustTry>, // For local references
+		JustTry,
+	>,
+	ConvertedConcreteId<
+		AssetId,
+		Balance,
+		AsForeignToLocal<AssetId, XcAssetConfig>, // For remote references (foreign)
+		JustTry,
+	>,
+);
+
 /// Means for transacting local assets besides the native currency on this chain.
 pub type FungiblesTransactor = FungiblesAdapter<
 	// Use this fungibles implementation:
 	Assets,
 	// Use this currency when it is a fungible asset matching the given location or name:
-	(
-		ConvertedConcreteId<
-			AssetId,
-			Balance,
-			AsPrefixedGeneralIndex<SelfAssetsPalletLocation, AssetId, JustTry>, // For local references
-			JustTry,
-		>,
-		ConvertedConcreteId<
-			AssetId,
-			Balance,
-			AsForeignToLocal<StatemintAssetsPalletLocation, FUSD, AssetId, JustTry>, // For remote references (foreign)
-			JustTry,
-		>,
-	),
+	FungiblesMatcher,
 	// Convert an XCM MultiLocation into a local account id:
 	LocationToAccountId,
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -141,8 +146,17 @@ pub type FungiblesTransactor = FungiblesAdapter<
 	CheckingAccount,
 >;
 
-/// Means for transacting assets on this chain.
-pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
+/// Means for transacting assets on this chain. Assets requiring a credential are only deposited
+/// to accounts holding it, see `pallet_asset_policy`.
+pub type AssetTransactors = (
+	CurrencyTransactor,
+	pallet_asset_policy::CredentialedDeposits<
+		Runtime,
+		FungiblesTransactor,
+		FungiblesMatcher,
+		LocationToAccountId,
+	>,
+);
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
@@ -166,11 +180,13 @@ pub type XcmOriginToTransactDispatchOrigin = (
 );
 
 parameter_types! {
-	// One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
-	pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 	pub const MaxInstructions: u32 = 100;
 }
 
+/// Weighs XCM messages instruction by instruction using the benchmarked XCM weights.
+pub type XcmWeigher =
+	WeightInfoBounds<weights::xcm::WatrXcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
+
 match_types! {
 	pub type Statemint: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 1, interior: X1(Parachain(1000)) }
@@ -181,7 +197,7 @@ match_types! {
 }
 
 pub type Barrier = DenyThenTry<
-	DenyReserveTransferToRelayChain,
+	DenyReserveDepositToRelayChain,
 	(
 		TakeWeightCredit,
 		AllowTopLevelPaidExecutionFrom<Everything>,
@@ -192,7 +208,40 @@ pub type Barrier = DenyThenTry<
 	),
 >;
 
-pub type Reserves = (NativeAsset, ConcreteNativeAssetFrom<StatemintLocation>);
+pub type Reserves = (NativeAsset, ConcreteNativeAssetFrom<AssetId, XcAssetConfig>);
+
+/// Calls that may be dispatched through XCM `Transact`.
+///
+/// Batches are only allowed if every call in them is, as the batched calls are dispatched
+/// without going through this filter again.
+pub struct SafeCallFilter;
+impl Contains<RuntimeCall> for SafeCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { .. }
+				| pallet_balances::Call::transfer_keep_alive { .. }
+				| pallet_balances::Call::transfer_all { .. },
+			) => true,
+			RuntimeCall::DID(
+				pallet_did::Call::create_did { .. }
+				| pallet_did::Call::update_did { .. }
+				| pallet_did::Call::remove_did { .. }
+				| pallet_did::Call::add_did_services { .. }
+				| pallet_did::Call::remove_did_services { .. }
+				| pallet_did::Call::issue_credentials { .. }
+				| pallet_did::Call::revoke_credentials { .. }
+				| pallet_did::Call::query_credential { .. },
+			) => true,
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls }
+				| pallet_utility::Call::batch_all { calls }
+				| pallet_utility::Call::force_batch { calls },
+			) => calls.iter().all(Self::contains),
+			_ => false,
+		}
+	}
+}
 
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
@@ -206,17 +255,18 @@ impl xcm_executor::Config for XcmConfig {
 	type IsTeleporter = ();
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type Trader = (
-		FixedRateOfFungible<
-			FUSDperSecond,
+		// Foreign assets at the rate set in `pallet_xc_asset_config`
+		FixedRateOfForeignAsset<
+			XcAssetConfig,
 			XcmFeesTo32ByteAccount<FungiblesTransactor, AccountId, XcmAssetFeesReceiver>,
 		>,
 		UsingComponents<WeightToFee, SelfReserve, AccountId, Balances, DealWithFees<Runtime>>,
 	);
 	type ResponseHandler = PolkadotXcm;
-	type AssetTrap = PolkadotXcm;
-	type AssetClaims = PolkadotXcm;
+	type AssetTrap = TrappedAssets;
+	type AssetClaims = TrappedAssets;
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
@@ -226,7 +276,7 @@ impl xcm_executor::Config for XcmConfig {
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
-	type SafeCallFilter = Everything;
+	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
 }
 
@@ -242,8 +292,17 @@ pub type XcmRouter = (
 	XcmpQueue,
 );
 
-pub type XcmExecuteFilter =
-	AllowOnlySendToReservePerAsset<SelfReserve, StatemintAssetsPalletLocation, FUSD>;
+pub type XcmExecuteFilter = (
+	// Up to two assets, so fees can be paid in another asset than the one transferred
+	AllowOnlySendToReservePerAsset<
+		SelfReserve,
+		AssetId,
+		XcAssetConfig,
+		AccountBeneficiaries,
+		ConstU32<2>,
+	>,
+	AllowOnlySendNativeToSiblings<UniversalLocation, AccountBeneficiaries>,
+);
 
 #[cfg(feature = "runtime-benchmarks")]
 parameter_types! {
@@ -258,12 +317,13 @@ impl pallet_xcm::Config for Runtime {
 	// We support local origins dispatching XCM executions in principle...
 	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
 	// ... but disallow arbitrary XCM messages execution.
-	// As a result only reserve transfers back to the reserve of specific assets are allowed.
+	// As a result only reserve transfers back to the reserve of specific assets, and of the native
+	// asset to sibling parachains, are allowed.
 	type XcmExecuteFilter = XcmExecuteFilter;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = Nothing;
 	type XcmReserveTransferFilter = Nothing;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type UniversalLocation = UniversalLocation;
@@ -295,7 +355,8 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type ChannelInfo = ParachainSystem;
 	type VersionWrapper = PolkadotXcm;
 	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
-	type ControllerOrigin = EnsureRoot<AccountId>;
+	// The technical committee can suspend XCM execution in an emergency
+	type ControllerOrigin = EitherOfDiverse<EnsureRoot<AccountId>, MoreThanHalfTechnicalCommittee>;
 	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
 	type PriceForSiblingDelivery = ();
@@ -306,3 +367,292 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn account(seed: u8) -> AccountId {
+		AccountId::from([seed; 32])
+	}
+
+	fn transfer() -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: account(1).into(),
+			value: 1,
+		})
+	}
+
+	fn remark() -> RuntimeCall {
+		RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+	}
+
+	#[test]
+	fn allows_balance_transfers() {
+		assert!(SafeCallFilter::contains(&transfer()));
+		assert!(SafeCallFilter::contains(&RuntimeCall::Balances(
+			pallet_balances::Call::transfer_allow_death { dest: account(1).into(), value: 1 }
+		)));
+		assert!(SafeCallFilter::contains(&RuntimeCall::Balances(
+			pallet_balances::Call::transfer_all { dest: account(1).into(), keep_alive: true }
+		)));
+	}
+
+	#[test]
+	fn allows_credential_queries() {
+		assert!(SafeCallFilter::contains(&RuntimeCall::DID(pallet_did::Call::query_credential {
+			query_id: 0,
+			did: account(1),
+			credential: b"KYCpassed".to_vec().try_into().unwrap(),
+			issuer: account(2),
+			max_response_weight: frame_support::weights::Weight::zero(),
+		})));
+	}
+
+	#[test]
+	fn allows_batches_of_allowed_calls() {
+		let calls = vec![transfer(), transfer()];
+		assert!(SafeCallFilter::contains(&RuntimeCall::Utility(pallet_utility::Call::batch {
+			calls: calls.clone()
+		})));
+		assert!(SafeCallFilter::contains(&RuntimeCall::Utility(pallet_utility::Call::batch_all {
+			calls: calls.clone()
+		})));
+		assert!(SafeCallFilter::contains(&RuntimeCall::Utility(
+			pallet_utility::Call::force_batch { calls }
+		)));
+	}
+
+	#[test]
+	fn rejects_batches_containing_disallowed_calls() {
+		let batch = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+			calls: vec![transfer(), remark()],
+		});
+		assert!(!SafeCallFilter::contains(&batch));
+
+		let nested = RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![batch] });
+		assert!(!SafeCallFilter::contains(&nested));
+	}
+
+	#[test]
+	fn rejects_system_calls() {
+		assert!(!SafeCallFilter::contains(&remark()));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::System(frame_system::Call::set_code {
+			code: vec![]
+		})));
+	}
+
+	#[test]
+	fn rejects_root_only_calls() {
+		// With sudo removed, these are the calls it used to be able to dispatch.
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Balances(
+			pallet_balances::Call::force_set_balance { who: account(1).into(), new_free: 1 }
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Utility(
+			pallet_utility::Call::dispatch_as {
+				as_origin: Box::new(frame_system::RawOrigin::Root.into()),
+				call: Box::new(transfer()),
+			}
+		)));
+	}
+
+	#[test]
+	fn rejects_governance_calls() {
+		let proposal = Box::new(remark());
+
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Council(
+			pallet_collective::Call::propose {
+				threshold: 1,
+				proposal: proposal.clone(),
+				length_bound: 100,
+			}
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::TechnicalCommittee(
+			pallet_collective::Call::propose {
+				threshold: 1,
+				proposal: proposal.clone(),
+				length_bound: 100,
+			}
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Motion(
+			pallet_motion::Call::simple_majority { call: proposal }
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Motion(pallet_motion::Call::fast_track {
+			motion_hash: Default::default()
+		})));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::CouncilMembership(
+			pallet_membership::Call::add_member { who: account(1).into() }
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::CircuitBreaker(
+			pallet_circuit_breaker::Call::pause_pallet {
+				pallet_name: b"Balances".to_vec().try_into().unwrap()
+			}
+		)));
+	}
+
+	#[test]
+	fn allows_did_management() {
+		assert!(SafeCallFilter::contains(&RuntimeCall::DID(pallet_did::Call::remove_did {
+			did: account(1)
+		})));
+		assert!(SafeCallFilter::contains(&RuntimeCall::DID(
+			pallet_did::Call::revoke_credentials {
+				issuer_did: account(1),
+				subject_did: account(2),
+				credentials: Default::default(),
+			}
+		)));
+	}
+
+	#[test]
+	fn rejects_did_governance_and_asset_calls() {
+		assert!(!SafeCallFilter::contains(&RuntimeCall::DID(pallet_did::Call::force_remove_did {
+			did: account(1)
+		})));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::DID(pallet_did::Call::add_issuer {
+			issuer: account(1)
+		})));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Assets(pallet_assets::Call::transfer {
+			id: 1u128.into(),
+			target: account(1).into(),
+			amount: 1,
+		})));
+	}
+
+	fn register_asset(asset_id: AssetId, location: MultiLocation) {
+		assert!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(xcm::VersionedMultiLocation::V3(location)),
+			asset_id,
+		)
+		.is_ok());
+	}
+
+	fn asset_hub_asset(index: u128) -> MultiLocation {
+		MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(index)))
+	}
+
+	fn asset_hub_asset_on_reserve(index: u128) -> MultiLocation {
+		MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(index)))
+	}
+
+	fn reserve_withdraw(
+		assets: Vec<MultiAsset>,
+		reserve: MultiLocation,
+		fees: MultiAsset,
+		beneficiary: Junction,
+	) -> (MultiLocation, Xcm<RuntimeCall>) {
+		let count = assets.len() as u32;
+		let origin = AccountId32 { network: Some(RelayNetwork::get()), id: [1; 32] }.into();
+		let message = Xcm(vec![
+			WithdrawAsset(assets.into()),
+			InitiateReserveWithdraw {
+				assets: Wild(AllCounted(count)),
+				reserve,
+				xcm: Xcm(vec![
+					BuyExecution { fees, weight_limit: Unlimited },
+					DepositAsset {
+						assets: Wild(AllCounted(count)),
+						beneficiary: beneficiary.into(),
+					},
+				]),
+			},
+		]);
+		(origin, message)
+	}
+
+	#[test]
+	fn allows_reserve_withdraw_with_fees_in_another_asset() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			register_asset(1, asset_hub_asset(1984));
+			register_asset(2, asset_hub_asset(1337));
+
+			assert!(XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![(asset_hub_asset(1984), 100).into(), (asset_hub_asset(1337), 10).into()],
+				MultiLocation::new(1, X1(Parachain(1000))),
+				(asset_hub_asset_on_reserve(1337), 10).into(),
+				AccountKey20 { network: None, key: [2; 20] },
+			)));
+		});
+	}
+
+	#[test]
+	fn allows_clear_origin_and_topics_in_reserve_withdraw() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			register_asset(3, MultiLocation::parent());
+
+			let origin = AccountId32 { network: Some(RelayNetwork::get()), id: [1; 32] }.into();
+			let message = Xcm(vec![
+				WithdrawAsset((Parent, 100).into()),
+				ClearOrigin,
+				InitiateReserveWithdraw {
+					assets: Wild(All),
+					reserve: Parent.into(),
+					xcm: Xcm(vec![
+						ClearOrigin,
+						BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+						DepositAsset {
+							assets: Wild(All),
+							beneficiary: AccountId32 { network: None, id: [2; 32] }.into(),
+						},
+						SetTopic([3; 32]),
+					]),
+				},
+				SetTopic([3; 32]),
+			]);
+			assert!(XcmExecuteFilter::contains(&(origin, message)));
+		});
+	}
+
+	#[test]
+	fn rejects_invalid_reserve_withdraws() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			register_asset(1, asset_hub_asset(1984));
+			register_asset(2, asset_hub_asset(1337));
+			register_asset(3, MultiLocation::parent());
+			register_asset(4, asset_hub_asset(1));
+			let asset_hub = MultiLocation::new(1, X1(Parachain(1000)));
+			let beneficiary = AccountId32 { network: None, id: [2; 32] };
+
+			// Assets with different reserves
+			assert!(!XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![(asset_hub_asset(1984), 100).into(), (Parent, 10).into()],
+				asset_hub,
+				(asset_hub_asset_on_reserve(1984), 10).into(),
+				beneficiary,
+			)));
+			// Fees above the withdrawn amount
+			assert!(!XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![(asset_hub_asset(1984), 100).into(), (asset_hub_asset(1337), 10).into()],
+				asset_hub,
+				(asset_hub_asset_on_reserve(1337), 11).into(),
+				beneficiary,
+			)));
+			// Unregistered asset
+			assert!(!XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![(asset_hub_asset(7), 100).into()],
+				asset_hub,
+				(asset_hub_asset_on_reserve(7), 10).into(),
+				beneficiary,
+			)));
+			// Too many assets
+			assert!(!XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![
+					(asset_hub_asset(1984), 100).into(),
+					(asset_hub_asset(1337), 10).into(),
+					(asset_hub_asset(1), 10).into(),
+				],
+				asset_hub,
+				(asset_hub_asset_on_reserve(1337), 10).into(),
+				beneficiary,
+			)));
+			// Beneficiary is not an account
+			assert!(!XcmExecuteFilter::contains(&reserve_withdraw(
+				vec![(asset_hub_asset(1984), 100).into()],
+				asset_hub,
+				(asset_hub_asset_on_reserve(1984), 10).into(),
+				Parachain(2000),
+			)));
+		});
+	}
+}
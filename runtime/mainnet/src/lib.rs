@@ -69,9 +69,10 @@ pub use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{
-		fungibles::Balanced, AsEnsureOriginWithArg, ConstU32, ConstU8, Currency as CurrencyT,
-		EitherOfDiverse, Everything, FindAuthor, Imbalance, InstanceFilter, KeyOwnerProofSystem,
-		LockIdentifier, OnFinalize, OnRuntimeUpgrade, OnUnbalanced, PrivilegeCmp,
+		fungibles::Balanced, AsEnsureOriginWithArg, ConstU32, ConstU8, Contains,
+		Currency as CurrencyT, EitherOfDiverse, Everything, FindAuthor, Imbalance, InsideBoth,
+		InstanceFilter, KeyOwnerProofSystem, LockIdentifier, OnFinalize, OnRuntimeUpgrade,
+		OnUnbalanced, PrivilegeCmp,
 	},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
@@ -102,6 +103,7 @@ use precompiles::{FrontierPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX};
 // Polkadot imports
 pub use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
 
+use watr_common::migrations::{RegisterXcAssets, RemoveSudo, RunOnce, SetXcAssetUnitsPerSecond};
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 
 /// The address format for describing accounts.
@@ -151,8 +153,44 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+parameter_types! {
+	/// Assets whose ERC20 precompile could only be called by the asset issuer before the
+	/// restrictions were moved to `pallet_asset_policy`.
+	pub IssuerOnlyAssetPrecompiles: Vec<AssetId> = vec![precompiles::NUSD_PRECOMPILE_ID];
+	/// Foreign assets the XCM config mapped to their reserve location before the mapping was
+	/// moved to `pallet_xc_asset_config`.
+	pub ForeignAssetLocations: Vec<(AssetId, xcm::latest::MultiLocation)> =
+		vec![(1984, xcm_config::FUSDLocation::get())];
+	/// Fee rates of the foreign assets the XCM trader used to hard-code, in units per second.
+	pub ForeignAssetUnitsPerSecond: Vec<(xcm::latest::MultiLocation, u128)> =
+		vec![(xcm_config::FUSDLocation::get(), xcm_config::default_fee_per_second() * 10)];
+	pub const RemoveSudoMigration: &'static str = "RemoveSudo";
+	pub const RegisterXcAssetsMigration: &'static str = "RegisterXcAssets";
+	pub const SetXcAssetUnitsPerSecondMigration: &'static str = "SetXcAssetUnitsPerSecond";
+}
+
+/// Migrations to apply on runtime upgrade.
+///
+/// The one-off migrations only run on the first upgrade including them, and must be removed once
+/// released.
+pub type Migrations = (
+	pallet_asset_policy::migrations::v1::MigrateToV1<Runtime, IssuerOnlyAssetPrecompiles>,
+	RunOnce<RemoveSudoMigration, RemoveSudo<Runtime, CouncilCollective>, RocksDbWeight>,
+	RunOnce<
+		RegisterXcAssetsMigration,
+		RegisterXcAssets<Runtime, ForeignAssetLocations>,
+		RocksDbWeight,
+	>,
+	RunOnce<
+		SetXcAssetUnitsPerSecondMigration,
+		SetXcAssetUnitsPerSecond<Runtime, ForeignAssetUnitsPerSecond>,
+		RocksDbWeight,
+	>,
+);
+
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
 ///
@@ -343,7 +381,10 @@ impl frame_system::Config for Runtime {
 	/// The weight of database operations that the runtime can invoke.
 	type DbWeight = RocksDbWeight;
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = InsideBoth<
+		pallet_asset_policy::CredentialedTransfers<Runtime>,
+		pallet_circuit_breaker::NotPaused<Runtime>,
+	>;
 	/// Weight information for the extrinsics of this pallet.
 	type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
 	//type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
@@ -361,12 +402,6 @@ impl frame_system::Config for Runtime {
 	type Block = Block;
 }
 
-impl pallet_sudo::Config for Runtime {
-	type RuntimeCall = RuntimeCall;
-	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
-}
-
 parameter_types! {
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }
@@ -483,8 +518,8 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 100;
 }
 
-// We allow root only to execute privileged collator selection operations.
-pub type CollatorSelectionUpdateOrigin = EnsureRoot<AccountId>;
+// Privileged collator selection operations are up to council motions.
+pub type CollatorSelectionUpdateOrigin = MotionOrigin;
 
 impl pallet_collator_selection::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -717,7 +752,7 @@ impl pallet_assets::Config for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
+	type Freezer = AssetPolicy;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets::WeightInfo<Runtime>;
 	type AssetAccountDeposit = AssetAccountDeposit;
@@ -746,10 +781,69 @@ impl pallet_xc_asset_config::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
 	type XcAssetChanged = EvmRevertCodeHandler;
-	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type ManagerOrigin = MotionOrigin;
 	type WeightInfo = weights::pallet_xc_asset_config::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const RelayHrmpPalletIndex: u8 = 60;
+	// In the relay chain asset, the surplus is refunded to the sovereign account
+	pub const HrmpRelayFee: u128 = 10_000_000_000;
+	pub HrmpRelayCallWeight: Weight = Weight::from_parts(1_000_000_000, 200_000);
+	pub const MaxPendingHrmpChannels: u32 = 8;
+}
+
+/// Reads the HRMP channels of this parachain from the relay chain state of the current block.
+pub struct RelayHrmpChannels;
+impl pallet_hrmp_manager::RelayChannels for RelayHrmpChannels {
+	fn is_open(channel: &pallet_hrmp_manager::HrmpChannelId) -> bool {
+		let state = match ParachainSystem::relevant_messaging_state() {
+			Some(state) => state,
+			None => return false,
+		};
+		let (channels, other) = if channel.sender == ParachainInfo::parachain_id() {
+			(state.egress_channels, channel.recipient)
+		} else {
+			(state.ingress_channels, channel.sender)
+		};
+		channels.iter().any(|(para_id, _)| *para_id == other)
+	}
+}
+
+impl pallet_hrmp_manager::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ChannelManagerOrigin = MotionOrigin;
+	type XcmSender = xcm_config::XcmRouter;
+	type SelfParaId = ParachainInfo;
+	type RelayChannels = RelayHrmpChannels;
+	type HrmpPalletIndex = RelayHrmpPalletIndex;
+	type RelayFee = HrmpRelayFee;
+	type RelayCallWeight = HrmpRelayCallWeight;
+	type MaxPendingChannels = MaxPendingHrmpChannels;
+	type WeightInfo = pallet_hrmp_manager::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_trapped_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTrap = PolkadotXcm;
+	type AssetClaims = PolkadotXcm;
+	type XcmExecutor = xcm_executor::XcmExecutor<xcm_config::XcmConfig>;
+	type RecoveryOrigin = MotionOrigin;
+	type WeightInfo = pallet_trapped_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TrappedAssetsBenchmarkHelper;
+}
+
+/// Recovers trapped native currency.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TrappedAssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_trapped_assets::BenchmarkHelper for TrappedAssetsBenchmarkHelper {
+	fn trapped_asset() -> xcm::latest::MultiAsset {
+		(xcm::latest::Junctions::Here, WATR).into()
+	}
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -810,7 +904,7 @@ impl pallet_preimage::Config for Runtime {
 	type WeightInfo = weights::pallet_preimage::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type ManagerOrigin = EnsureRoot<AccountId>;
+	type ManagerOrigin = MotionOrigin;
 	type BaseDeposit = PreimageBaseDeposit;
 	type ByteDeposit = PreimageByteDeposit;
 }
@@ -845,11 +939,26 @@ macro_rules! prod_or_fast {
 	};
 }
 
-type MoreThanHalfCouncil = EitherOfDiverse<
+pub type MoreThanHalfCouncil = EitherOfDiverse<
 	EnsureRoot<AccountId>,
 	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 >;
 
+/// Origin of privileged operations: any council motion, dispatched once the delay of its majority
+/// passed. The council can not use these operations directly, bypassing the delay.
+pub type MotionOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_motion::EnsureMotion<pallet_motion::types::SimpleMajorityLevel>,
+>;
+
+/// Origin allowed to change the members of the council and of the technical committee: a super
+/// majority or unanimous motion. Those dispatch with `Root`, which simple majority motions can
+/// not use for these calls, see [`SimpleMajorityMotionCalls`].
+pub type MembershipOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_motion::EnsureMotion<pallet_motion::types::SuperMajorityLevel>,
+>;
+
 parameter_types! {
 	pub CouncilMotionDuration: BlockNumber = prod_or_fast!(7 * DAYS, 2 * MINUTES, "WATR_MOTION_DURATION");
 	pub const CouncilMaxProposals: u32 = 100;
@@ -867,17 +976,17 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
-	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type SetMembersOrigin = MembershipOrigin;
 	type MaxProposalWeight = MaxProposalWeight;
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 }
 
 impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type AddOrigin = MoreThanHalfCouncil;
-	type RemoveOrigin = MoreThanHalfCouncil;
-	type SwapOrigin = MoreThanHalfCouncil;
-	type ResetOrigin = MoreThanHalfCouncil;
+	type AddOrigin = MembershipOrigin;
+	type RemoveOrigin = MembershipOrigin;
+	type SwapOrigin = MembershipOrigin;
+	type ResetOrigin = MembershipOrigin;
 	type PrimeOrigin = MoreThanHalfCouncil;
 	type MembershipInitialized = Council;
 	type MembershipChanged = Council;
@@ -885,6 +994,149 @@ impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 	type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub TechnicalMotionDuration: BlockNumber = prod_or_fast!(3 * DAYS, 2 * MINUTES, "WATR_TECHNICAL_MOTION_DURATION");
+	pub const TechnicalMaxProposals: u32 = 100;
+	pub const TechnicalMaxMembers: u32 = 100;
+}
+
+pub type TechnicalCollective = pallet_collective::Instance2;
+
+/// Technical committee proposal approved by more than half of the committee.
+pub type MoreThanHalfTechnicalCommittee =
+	pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>;
+/// Technical committee proposal approved by at least two thirds of the committee.
+pub type TwoThirdsTechnicalCommittee =
+	pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>;
+/// Technical committee proposal approved by the whole committee.
+pub type UnanimousTechnicalCommittee =
+	pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 1>;
+
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = TechnicalMotionDuration;
+	type MaxProposals = TechnicalMaxProposals;
+	type MaxMembers = TechnicalMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type SetMembersOrigin = MembershipOrigin;
+	type MaxProposalWeight = MaxProposalWeight;
+	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
+}
+
+// The council decides who sits on the technical committee
+impl pallet_membership::Config<pallet_membership::Instance2> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = MembershipOrigin;
+	type RemoveOrigin = MembershipOrigin;
+	type SwapOrigin = MembershipOrigin;
+	type ResetOrigin = MembershipOrigin;
+	type PrimeOrigin = MoreThanHalfCouncil;
+	type MembershipInitialized = TechnicalCommittee;
+	type MembershipChanged = TechnicalCommittee;
+	type MaxMembers = TechnicalMaxMembers;
+	type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub SimpleMajorityMotionDelay: BlockNumber = prod_or_fast!(2 * DAYS, 4 * MINUTES, "WATR_SIMPLE_MAJORITY_DELAY");
+	pub SuperMajorityMotionDelay: BlockNumber = prod_or_fast!(DAYS, 2 * MINUTES, "WATR_SUPER_MAJORITY_DELAY");
+	pub UnanimousMotionDelay: BlockNumber = prod_or_fast!(HOURS, MINUTES, "WATR_UNANIMOUS_DELAY");
+	pub const MaxMotionCancelReasonLength: u32 = 256;
+	pub const MaxMotionBatchCalls: u32 = 32;
+	pub const MaxMotionHistoryLength: u32 = 256;
+	pub const MotionCancellationRetention: BlockNumber = 30 * DAYS;
+	/// Largest amount simple majority motions can spend from the treasury in a spend period, in
+	/// total.
+	pub const SimpleMajorityMaxSpend: Balance = 10_000 * WATR;
+}
+
+/// The spend period of the last treasury spend approved by a simple majority motion, and the
+/// amount simple majority motions spent in it.
+#[frame_support::storage_alias]
+pub type SimpleMajoritySpent = StorageValue<Motion, (BlockNumber, Balance), ValueQuery>;
+
+/// Calls a simple majority motion can dispatch: remarks, ordinary governance of the Watr pallets
+/// and treasury spends, which are capped by `SimpleMajorityMaxSpend` per spend period across all
+/// the motions. Every other call, including runtime upgrades, storage changes, forced transfers,
+/// XCM messages and changes to the council or technical committee membership, requires a super
+/// majority or unanimity. Motion calls are refused so a simple majority can not reach a higher
+/// majority level by nesting them.
+///
+/// It is only checked when a motion is approved, so the treasury spends of the calls it accepts
+/// are recorded in `SimpleMajoritySpent`. They are reverted along with the approval if it fails.
+pub struct SimpleMajorityMotionCalls;
+impl Contains<RuntimeCall> for SimpleMajorityMotionCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::System(
+				frame_system::Call::remark { .. } | frame_system::Call::remark_with_event { .. },
+			)
+			| RuntimeCall::Treasury(
+				pallet_treasury::Call::reject_proposal { .. }
+				| pallet_treasury::Call::remove_approval { .. },
+			)
+			| RuntimeCall::CollatorSelection(..)
+			| RuntimeCall::Preimage(..)
+			| RuntimeCall::XcAssetConfig(..)
+			| RuntimeCall::HrmpManager(..)
+			| RuntimeCall::TrappedAssets(..)
+			| RuntimeCall::DID(..)
+			| RuntimeCall::AssetPolicy(..)
+			| RuntimeCall::CircuitBreaker(..) => true,
+			RuntimeCall::Treasury(pallet_treasury::Call::spend { amount, .. }) => {
+				Self::try_spend(*amount)
+			},
+			// Calls batched in a utility call are checked as well
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls }
+				| pallet_utility::Call::batch_all { calls }
+				| pallet_utility::Call::force_batch { calls },
+			) => calls.iter().all(Self::contains),
+			_ => false,
+		}
+	}
+}
+
+impl SimpleMajorityMotionCalls {
+	/// Records `amount` as spent in the current spend period. Returns false, recording nothing,
+	/// if it exceeds what is left of `SimpleMajorityMaxSpend`.
+	fn try_spend(amount: Balance) -> bool {
+		let period = System::block_number() / SpendPeriod::get();
+		SimpleMajoritySpent::mutate(|(spent_period, spent)| {
+			if *spent_period != period {
+				*spent_period = period;
+				*spent = 0;
+			}
+			match spent.checked_add(amount) {
+				Some(total) if total <= SimpleMajorityMaxSpend::get() => {
+					*spent = total;
+					true
+				},
+				_ => false,
+			}
+		})
+	}
+}
+
+/// Runtime upgrades, the only calls the technical committee can fast-track.
+pub struct RuntimeUpgradeCalls;
+impl Contains<RuntimeCall> for RuntimeUpgradeCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::System(
+				frame_system::Call::set_code { .. }
+					| frame_system::Call::set_code_without_checks { .. }
+			) | RuntimeCall::ParachainSystem(
+				cumulus_pallet_parachain_system::Call::authorize_upgrade { .. }
+					| cumulus_pallet_parachain_system::Call::enact_authorized_upgrade { .. }
+			)
+		)
+	}
+}
+
 impl pallet_motion::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
@@ -894,6 +1146,25 @@ impl pallet_motion::Config for Runtime {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
 	type UnanimousOrigin =
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SimpleMajorityDelay = SimpleMajorityMotionDelay;
+	type SuperMajorityDelay = SuperMajorityMotionDelay;
+	type UnanimousDelay = UnanimousMotionDelay;
+	type SimpleMajorityCalls = SimpleMajorityMotionCalls;
+	type SuperMajorityCalls = Everything;
+	type UnanimousCalls = Everything;
+	type VetoOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type MaxReasonLength = MaxMotionCancelReasonLength;
+	// Placeholder weights: `pallet_motion` has not been benchmarked on reference hardware yet,
+	// see `weights::pallet_motion`.
+	type WeightInfo = weights::pallet_motion::WeightInfo<Runtime>;
+	type MaxBatchCalls = MaxMotionBatchCalls;
+	type MotionOrigin = pallet_motion::AsMotionOrigin<RuntimeOrigin>;
+	type CollectiveProposals = pallet_motion::CollectiveProposalIndex<Runtime, CouncilCollective>;
+	type MaxHistoryLength = MaxMotionHistoryLength;
+	type FastTrackOrigin = TwoThirdsTechnicalCommittee;
+	type FastTrackCalls = RuntimeUpgradeCalls;
+	type CancellationRetention = MotionCancellationRetention;
 }
 
 parameter_types! {
@@ -918,8 +1189,85 @@ impl pallet_did::Config for Runtime {
 	type MaxCredentialsTypes = MaxCredentialsTypes;
 	type MaxCredentialTypeLength = MaxCredentialTypeLength;
 	type MaxServices = MaxServices;
-	type GovernanceOrigin = MoreThanHalfCouncil;
+	type GovernanceOrigin = MotionOrigin;
+	// Credential queries arrive as XCM `Transact` with `OriginKind::Xcm`, from locations allowed
+	// by governance.
+	type CredentialQueryOrigin = pallet_xcm::EnsureXcm<Everything>;
+	type XcmSender = xcm_config::XcmRouter;
+	type UniversalLocation = xcm_config::UniversalLocation;
+	// Locations manage their DIDs through XCM `Transact` with `OriginKind::Xcm`, the DID being
+	// created for their sovereign account.
+	type XcmDidOrigin = pallet_xcm::EnsureXcm<Everything>;
+	type LocationToAccountId = xcm_config::LocationToAccountId;
 	type WeightInfo = weights::pallet_did::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DidBenchmarkHelper;
+}
+
+/// Queries credentials from the relay chain, which responses can always be sent to.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DidBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_did::BenchmarkHelper<RuntimeOrigin> for DidBenchmarkHelper {
+	fn credential_querier() -> RuntimeOrigin {
+		pallet_xcm::Origin::Xcm(xcm::latest::MultiLocation::parent()).into()
+	}
+}
+
+parameter_types! {
+	pub const MaxAllowedPrecompileCallers: u32 = 16;
+	pub const MaxTrustedCredentialIssuers: u32 = 16;
+}
+
+impl pallet_asset_policy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Assets = Assets;
+	type PolicyOrigin = MotionOrigin;
+	type MaxAllowedCallers = MaxAllowedPrecompileCallers;
+	type MaxTrustedIssuers = MaxTrustedCredentialIssuers;
+	type WeightInfo = pallet_asset_policy::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxPauseNameLength: u32 = 64;
+}
+
+/// Calls the circuit breaker never pauses: inherents, and the calls governance needs to resolve
+/// an incident.
+///
+/// The base call filter also applies to collective origins, so a council proposal calling into a
+/// paused pallet directly is filtered. Remediation calls must be wrapped in a motion, which
+/// dispatches them bypassing the filter.
+pub struct UnpausableCalls;
+impl Contains<RuntimeCall> for UnpausableCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::System(..)
+				| RuntimeCall::ParachainSystem(..)
+				| RuntimeCall::Timestamp(..)
+				| RuntimeCall::Council(..)
+				| RuntimeCall::CouncilMembership(..)
+				| RuntimeCall::TechnicalCommittee(..)
+				| RuntimeCall::TechnicalMembership(..)
+				| RuntimeCall::Motion(..)
+		)
+	}
+}
+
+impl pallet_circuit_breaker::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PauseOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EitherOfDiverse<
+			MoreThanHalfTechnicalCommittee,
+			pallet_motion::EnsureMotion<pallet_motion::types::UnanimousLevel>,
+		>,
+	>;
+	type UnpausableCalls = UnpausableCalls;
+	type MaxNameLength = MaxPauseNameLength;
+	type WeightInfo = pallet_circuit_breaker::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1123,6 +1471,8 @@ construct_runtime!(
 		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
 		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 33,
 		XcAssetConfig: pallet_xc_asset_config::{Pallet, Call, Storage, Event<T>} = 34,
+		HrmpManager: pallet_hrmp_manager::{Pallet, Call, Storage, Event<T>} = 35,
+		TrappedAssets: pallet_trapped_assets::{Pallet, Call, Storage, Event<T>} = 36,
 
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 41,
 		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 42,
@@ -1130,9 +1480,11 @@ construct_runtime!(
 
 		//Governance
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>} = 44,
-		Motion: pallet_motion::{Pallet, Call, Storage,  Event<T>} = 45,
+		Motion: pallet_motion::{Pallet, Call, Storage, Origin, Event<T>} = 45,
 		CouncilMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 46,
 		Treasury: pallet_treasury::{Pallet, Call, Storage, Config<T>, Event<T>} = 47,
+		TechnicalCommittee: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>} = 53,
+		TechnicalMembership: pallet_membership::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>} = 54,
 
 		//Assets
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>, Config<T>} = 48,
@@ -1147,8 +1499,9 @@ construct_runtime!(
 
 		// DID
 		DID: pallet_did::{Pallet, Call, Storage, Event<T>} = 60,
+		AssetPolicy: pallet_asset_policy::{Pallet, Call, Storage, Event<T>, Config<T>} = 61,
 
-		Sudo: pallet_sudo::{Pallet, Call, Storage, Config<T>, Event<T>} = 255,
+		CircuitBreaker: pallet_circuit_breaker::{Pallet, Call, Storage, Event<T>} = 62,
 	}
 );
 
@@ -1220,7 +1573,6 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_assets, Assets]
 		[pallet_balances, Balances]
-		[pallet_sudo, Sudo]
 		[pallet_multisig, Multisig]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_utility, Utility]
@@ -1228,15 +1580,24 @@ mod benches {
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_collective, Council]
+		[pallet_collective, TechnicalCommittee]
 		[pallet_identity, Identity]
 		[pallet_proxy, Proxy]
 		[pallet_scheduler, Scheduler]
 		[pallet_treasury, Treasury]
 		[pallet_membership, CouncilMembership]
+		[pallet_membership, TechnicalMembership]
 		[pallet_preimage, Preimage]
 		[pallet_did, DID]
+		[pallet_asset_policy, AssetPolicy]
+		[pallet_motion, Motion]
+		[pallet_circuit_breaker, CircuitBreaker]
 		[pallet_xc_asset_config, XcAssetConfig]
+		[pallet_hrmp_manager, HrmpManager]
+		[pallet_trapped_assets, TrappedAssets]
 		[pallet_block_reward, BlockReward]
+		[pallet_xcm_benchmarks::fungible, XcmBalances]
+		[pallet_xcm_benchmarks::generic, XcmGeneric]
 	);
 }
 
@@ -1528,6 +1889,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_motion_runtime_api::MotionApi<Block, Hash, BlockNumber> for Runtime {
+		fn executed_motions() -> Vec<pallet_motion::types::ExecutedMotion<Hash, BlockNumber>> {
+			Motion::executed_motions()
+		}
+	}
+
+	impl pallet_trapped_assets_runtime_api::TrappedAssetsApi<Block> for Runtime {
+		fn trapped_assets(
+			origin: xcm::VersionedMultiLocation,
+		) -> Vec<(H256, pallet_trapped_assets::types::AssetTrap)> {
+			TrappedAssets::trapped_assets(origin)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -1564,6 +1939,8 @@ impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
+			type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+			type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
 
 			let mut list = Vec::<BenchmarkList>::new();
 			list_benchmarks!(list, extra);
@@ -1575,7 +1952,7 @@ impl_runtime_apis! {
 		fn dispatch_benchmark(
 			config: frame_benchmarking::BenchmarkConfig
 		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
-			use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey, BenchmarkError};
+			use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey, BenchmarkError, BenchmarkResult};
 
 			use frame_system_benchmarking::Pallet as SystemBench;
 			impl frame_system_benchmarking::Config for Runtime {
@@ -1592,6 +1969,103 @@ impl_runtime_apis! {
 			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
 			impl cumulus_pallet_session_benchmarking::Config for Runtime {}
 
+			use xcm::latest::prelude::*;
+			impl pallet_xcm_benchmarks::Config for Runtime {
+				type XcmConfig = xcm_config::XcmConfig;
+				type AccountIdConverter = xcm_config::LocationToAccountId;
+				fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+					Ok(xcm_config::RelayLocation::get())
+				}
+				fn worst_case_holding(depositable_count: u32) -> MultiAssets {
+					// Fill the holding register with local fungible assets and the native currency.
+					let holding_fungibles = xcm_config::MaxAssetsIntoHolding::get()
+						.saturating_sub(depositable_count)
+						.saturating_sub(1);
+					(0..holding_fungibles)
+						.map(|i| MultiAsset {
+							id: Concrete(GeneralIndex(i as u128).into()),
+							fun: Fungible(100 * (i as u128 + 1)),
+						})
+						.chain(core::iter::once(MultiAsset {
+							id: Concrete(Here.into()),
+							fun: Fungible(1_000_000 * WATR),
+						}))
+						.collect::<Vec<_>>()
+						.into()
+				}
+			}
+
+			parameter_types! {
+				pub const TrustedTeleporter: Option<(MultiLocation, MultiAsset)> = None;
+				pub const CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> = None;
+				// The relay chain is the reserve of its native asset, see `xcm_config::Reserves`.
+				pub TrustedReserve: Option<(MultiLocation, MultiAsset)> = Some((
+					xcm_config::RelayLocation::get(),
+					MultiAsset { id: Concrete(xcm_config::RelayLocation::get()), fun: Fungible(1_000_000_000_000) },
+				));
+			}
+
+			impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+				type TransactAsset = Balances;
+				type CheckedAccount = CheckedAccount;
+				// Teleporting is disabled.
+				type TrustedTeleporter = TrustedTeleporter;
+				type TrustedReserve = TrustedReserve;
+
+				fn get_multi_asset() -> MultiAsset {
+					MultiAsset { id: Concrete(Here.into()), fun: Fungible(WATR) }
+				}
+			}
+
+			impl pallet_xcm_benchmarks::generic::Config for Runtime {
+				type RuntimeCall = RuntimeCall;
+
+				fn worst_case_response() -> (u64, Response) {
+					(0u64, Response::Version(Default::default()))
+				}
+
+				// The instructions below are not supported by the executor, and are weighed at
+				// `Weight::MAX` so that messages using them are rejected up front.
+				fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+					Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+				}
+
+				fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+					Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+				}
+
+				fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+					Ok((xcm_config::RelayLocation::get(), frame_system::Call::remark_with_event { remark: vec![] }.into()))
+				}
+
+				fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+					Ok(xcm_config::RelayLocation::get())
+				}
+
+				fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+					let origin = xcm_config::RelayLocation::get();
+					let assets: MultiAssets = (Concrete(Here.into()), 1_000 * WATR).into();
+					let ticket = MultiLocation { parents: 0, interior: Here };
+					Ok((origin, ticket, assets))
+				}
+
+				fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+					Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+				}
+
+				fn export_message_origin_and_destination(
+				) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError> {
+					Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+				}
+
+				fn alias_origin() -> Result<(MultiLocation, MultiLocation), BenchmarkError> {
+					Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+				}
+			}
+
+			type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+			type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
 			let whitelist: Vec<TrackedStorageKey> = vec![
 				// Block Number
 				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec().into(),
@@ -1645,3 +2119,357 @@ cumulus_pallet_parachain_system::register_validate_block! {
 	BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
 	CheckInherents = CheckInherents,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, assert_ok,
+		dispatch::{Dispatchable, GetDispatchInfo},
+		traits::EnsureOrigin,
+	};
+
+	fn account(seed: u8) -> AccountId {
+		AccountId::from([seed; 32])
+	}
+
+	fn remark() -> RuntimeCall {
+		RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+	}
+
+	fn membership_calls() -> Vec<RuntimeCall> {
+		vec![
+			RuntimeCall::CouncilMembership(pallet_membership::Call::add_member {
+				who: account(1).into(),
+			}),
+			RuntimeCall::CouncilMembership(pallet_membership::Call::reset_members {
+				members: vec![account(1)],
+			}),
+			RuntimeCall::TechnicalMembership(pallet_membership::Call::swap_member {
+				remove: account(1).into(),
+				add: account(2).into(),
+			}),
+			RuntimeCall::Council(pallet_collective::Call::set_members {
+				new_members: vec![account(1)],
+				prime: None,
+				old_count: 0,
+			}),
+			RuntimeCall::TechnicalCommittee(pallet_collective::Call::set_members {
+				new_members: vec![account(1)],
+				prime: None,
+				old_count: 0,
+			}),
+		]
+	}
+
+	#[test]
+	fn simple_majority_can_not_change_membership() {
+		for call in membership_calls() {
+			assert!(!SimpleMajorityMotionCalls::contains(&call));
+			assert!(!SimpleMajorityMotionCalls::contains(&RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls: vec![remark(), call] }
+			)));
+		}
+	}
+
+	/// Calls as damaging as a runtime upgrade or an uncapped treasury spend when dispatched as
+	/// `Root`.
+	fn privileged_calls() -> Vec<RuntimeCall> {
+		use xcm::{latest::prelude::*, VersionedMultiLocation, VersionedXcm};
+
+		vec![
+			RuntimeCall::System(frame_system::Call::set_storage {
+				items: vec![(sp_core::storage::well_known_keys::CODE.to_vec(), vec![])],
+			}),
+			RuntimeCall::System(frame_system::Call::kill_storage {
+				keys: vec![sp_core::storage::well_known_keys::CODE.to_vec()],
+			}),
+			RuntimeCall::System(frame_system::Call::kill_prefix { prefix: vec![], subkeys: 0 }),
+			RuntimeCall::Balances(pallet_balances::Call::force_transfer {
+				source: Treasury::account_id().into(),
+				dest: account(1).into(),
+				value: 1,
+			}),
+			RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+				who: Treasury::account_id().into(),
+				new_free: 0,
+			}),
+			RuntimeCall::Assets(pallet_assets::Call::force_transfer {
+				id: 1,
+				source: Treasury::account_id().into(),
+				dest: account(1).into(),
+				amount: 1,
+			}),
+			RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+				dest: Box::new(VersionedMultiLocation::V3(Parent.into())),
+				message: Box::new(VersionedXcm::V3(Xcm(vec![]))),
+			}),
+			RuntimeCall::ParachainSystem(
+				cumulus_pallet_parachain_system::Call::sudo_send_upward_message { message: vec![] },
+			),
+		]
+	}
+
+	fn spend(amount: Balance) -> RuntimeCall {
+		RuntimeCall::Treasury(pallet_treasury::Call::spend {
+			amount,
+			beneficiary: account(1).into(),
+		})
+	}
+
+	#[test]
+	fn simple_majority_can_not_make_privileged_calls() {
+		for call in privileged_calls() {
+			assert!(!SimpleMajorityMotionCalls::contains(&call));
+			assert!(!SimpleMajorityMotionCalls::contains(&RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls: vec![remark(), call] }
+			)));
+		}
+	}
+
+	#[test]
+	fn simple_majority_spends_are_capped_across_motions() {
+		new_test_ext().execute_with(|| {
+			let half = SimpleMajorityMaxSpend::get() / 2;
+			assert!(SimpleMajorityMotionCalls::contains(&spend(half)));
+			assert!(SimpleMajorityMotionCalls::contains(&spend(half)));
+			assert!(!SimpleMajorityMotionCalls::contains(&spend(1)));
+
+			// The cap applies again from the next spend period
+			System::set_block_number(SpendPeriod::get());
+			assert!(SimpleMajorityMotionCalls::contains(&spend(SimpleMajorityMaxSpend::get())));
+		});
+	}
+
+	#[test]
+	fn simple_majority_spends_are_capped_across_batched_calls() {
+		new_test_ext().execute_with(|| {
+			let half = SimpleMajorityMaxSpend::get() / 2;
+			assert!(!SimpleMajorityMotionCalls::contains(&RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls: vec![spend(half), spend(half + 1)] }
+			)));
+
+			assert_noop!(
+				RuntimeCall::Motion(pallet_motion::Call::batch {
+					level: pallet_motion::types::MajorityLevel::SimpleMajority,
+					dispatch_as: pallet_motion::types::DispatchAs::Root,
+					calls: vec![spend(half), spend(half + 1)],
+					atomic: false,
+				})
+				.dispatch(council_origin(1)),
+				pallet_motion::Error::<Runtime>::CallNotAllowed
+			);
+			// Nothing is recorded for motions that were not approved
+			assert!(SimpleMajorityMotionCalls::contains(&spend(SimpleMajorityMaxSpend::get())));
+		});
+	}
+
+	#[test]
+	fn simple_majority_can_not_nest_motions() {
+		let motion = RuntimeCall::Motion(pallet_motion::Call::super_majority {
+			call: Box::new(membership_calls().remove(0)),
+		});
+		assert!(!SimpleMajorityMotionCalls::contains(&motion));
+		assert!(!SimpleMajorityMotionCalls::contains(&RuntimeCall::Utility(
+			pallet_utility::Call::dispatch_as {
+				as_origin: Box::new(frame_system::RawOrigin::Root.into()),
+				call: Box::new(motion),
+			}
+		)));
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn council_origin(yes_votes: u32) -> RuntimeOrigin {
+		pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes_votes, yes_votes)
+			.into()
+	}
+
+	#[test]
+	fn paused_pallets_are_remediated_through_motions() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CircuitBreaker::pause_pallet(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec().try_into().unwrap()
+			));
+			let call = Box::new(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+				who: account(1).into(),
+				new_free: WATR,
+			}));
+
+			// The base call filter applies to council proposals dispatching a call directly
+			assert_noop!(
+				(*call).clone().dispatch(council_origin(1)),
+				frame_system::Error::<Runtime>::CallFiltered
+			);
+
+			// Motions dispatch their calls bypassing it
+			let motion_hash = Motion::motion_hash(
+				&pallet_motion::types::MotionCall::Single(call.clone()),
+				pallet_motion::types::MajorityLevel::Unanimous,
+				pallet_motion::types::DispatchAs::Root,
+			);
+			assert_ok!(Motion::unanimous(council_origin(1), call.clone()));
+			System::set_block_number(1 + UnanimousMotionDelay::get());
+			assert_ok!(Motion::execute_ready(
+				RuntimeOrigin::signed(account(2)),
+				motion_hash,
+				call.get_dispatch_info().weight
+			));
+			assert_eq!(Balances::free_balance(account(1)), WATR);
+		});
+	}
+
+	#[test]
+	fn membership_changes_require_a_super_majority() {
+		type AddOrigin =
+			<Runtime as pallet_membership::Config<pallet_membership::Instance1>>::AddOrigin;
+		type SetMembersOrigin =
+			<Runtime as pallet_collective::Config<CouncilCollective>>::SetMembersOrigin;
+
+		let motion = |level| RuntimeOrigin::from(pallet_motion::RawOrigin::Motion(level));
+		for origin in
+			[council_origin(1), motion(pallet_motion::types::MajorityLevel::SimpleMajority)]
+		{
+			assert!(AddOrigin::try_origin(origin.clone()).is_err());
+			assert!(SetMembersOrigin::try_origin(origin).is_err());
+		}
+		for origin in
+			[RuntimeOrigin::root(), motion(pallet_motion::types::MajorityLevel::SuperMajority)]
+		{
+			assert!(AddOrigin::try_origin(origin.clone()).is_ok());
+			assert!(SetMembersOrigin::try_origin(origin).is_ok());
+		}
+	}
+
+	/// Checks `O` accepts motions only, and not the council directly.
+	fn assert_requires_motion<O: EnsureOrigin<RuntimeOrigin>>() {
+		let motion =
+			pallet_motion::RawOrigin::Motion(pallet_motion::types::MajorityLevel::SimpleMajority);
+		assert!(O::try_origin(council_origin(3)).is_err());
+		assert!(O::try_origin(RuntimeOrigin::from(motion)).is_ok());
+		assert!(O::try_origin(RuntimeOrigin::root()).is_ok());
+	}
+
+	#[test]
+	fn privileged_operations_require_a_motion() {
+		assert_requires_motion::<<Runtime as pallet_did::Config>::GovernanceOrigin>();
+		assert_requires_motion::<<Runtime as pallet_asset_policy::Config>::PolicyOrigin>();
+		assert_requires_motion::<<Runtime as pallet_hrmp_manager::Config>::ChannelManagerOrigin>();
+		assert_requires_motion::<<Runtime as pallet_trapped_assets::Config>::RecoveryOrigin>();
+		assert_requires_motion::<<Runtime as pallet_xc_asset_config::Config>::ManagerOrigin>();
+		assert_requires_motion::<<Runtime as pallet_preimage::Config>::ManagerOrigin>();
+		assert_requires_motion::<<Runtime as pallet_collator_selection::Config>::UpdateOrigin>();
+	}
+
+	#[test]
+	fn council_authorizes_upgrades_through_motions() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Council::set_members(
+				RuntimeOrigin::root(),
+				vec![account(1), account(2), account(3)],
+				None,
+				0
+			));
+
+			assert_ok!(watr_common::migrations::authorize_upgrade_through_council::<
+				Runtime,
+				CouncilCollective,
+			>(Hash::repeat_byte(1)));
+
+			assert!(System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::ParachainSystem(
+					cumulus_pallet_parachain_system::Event::UpgradeAuthorized { code_hash }
+				) if code_hash == Hash::repeat_byte(1)
+			)));
+		});
+	}
+
+	#[test]
+	fn one_off_migrations_only_run_once() {
+		type RegisterOnce = RunOnce<
+			RegisterXcAssetsMigration,
+			RegisterXcAssets<Runtime, ForeignAssetLocations>,
+			RocksDbWeight,
+		>;
+
+		new_test_ext().execute_with(|| {
+			assert!(RegisterOnce::on_runtime_upgrade().all_gt(RocksDbWeight::get().reads(1)));
+			assert!(XcAssetConfig::get_xc_asset_location(1984).is_some());
+			assert!(watr_common::migrations::AppliedMigrations::contains_key(
+				RegisterXcAssetsMigration::get().as_bytes().to_vec()
+			));
+
+			assert_eq!(RegisterOnce::on_runtime_upgrade(), RocksDbWeight::get().reads(1));
+		});
+	}
+
+	#[test]
+	fn simple_majority_can_dispatch_ordinary_calls() {
+		assert!(SimpleMajorityMotionCalls::contains(&remark()));
+		assert!(SimpleMajorityMotionCalls::contains(&RuntimeCall::Utility(
+			pallet_utility::Call::batch { calls: vec![remark()] }
+		)));
+		assert!(SimpleMajorityMotionCalls::contains(&RuntimeCall::CircuitBreaker(
+			pallet_circuit_breaker::Call::unpause_pallet {
+				pallet_name: b"Balances".to_vec().try_into().unwrap()
+			}
+		)));
+	}
+
+	#[test]
+	fn xcm_deposits_of_credentialed_assets_require_the_credential() {
+		use xcm::latest::prelude::*;
+		use xcm_executor::traits::TransactAsset;
+
+		new_test_ext().execute_with(|| {
+			let kyc: pallet_did::CredentialOf<Runtime> = b"kyc".to_vec().try_into().unwrap();
+			for asset_id in [1, 2] {
+				assert_ok!(Assets::force_create(
+					RuntimeOrigin::root(),
+					asset_id,
+					account(1).into(),
+					true,
+					1
+				));
+			}
+			assert_ok!(DID::add_credentials_type(
+				RuntimeOrigin::root(),
+				vec![kyc.clone()].try_into().unwrap()
+			));
+			assert_ok!(AssetPolicy::set_asset_credential(
+				RuntimeOrigin::root(),
+				1,
+				kyc,
+				vec![account(1)].try_into().unwrap()
+			));
+
+			let asset = |asset_id: u128| -> MultiAsset {
+				let location = xcm_config::SelfAssetsPalletLocation::get()
+					.pushed_with_interior(GeneralIndex(asset_id))
+					.unwrap();
+				(location, 10u128).into()
+			};
+			let beneficiary: MultiLocation =
+				AccountId32 { network: None, id: account(2).into() }.into();
+			let context = XcmContext { origin: None, message_id: [0; 32], topic: None };
+
+			assert!(xcm_config::AssetTransactors::deposit_asset(&asset(1), &beneficiary, &context)
+				.is_err());
+			assert_eq!(Assets::balance(1, account(2)), 0);
+
+			assert_ok!(xcm_config::AssetTransactors::deposit_asset(
+				&asset(2),
+				&beneficiary,
+				&context
+			));
+			assert_eq!(Assets::balance(2, account(2)), 10);
+		});
+	}
+}
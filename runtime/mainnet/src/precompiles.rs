@@ -33,8 +33,11 @@ use precompile_utils::error;
 use sp_core::H160;
 use sp_std::{fmt::Debug, marker::PhantomData};
 
-use crate::sp_api_hidden_includes_construct_runtime::hidden_include::traits::fungibles::roles::Inspect;
-use crate::AssetId;
+use pallet_batch_precompile::WatrBatchPrecompile;
+use pallet_governance_precompile::{AccountToAddress, WatrGovernancePrecompile};
+use pallet_xcm_precompile::WatrXcmPrecompile;
+
+use crate::{AssetId, CouncilCollective};
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
@@ -42,8 +45,21 @@ pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
 
 /// NUSD -> Native USD
 pub const NUSD_PRECOMPILE_ID: AssetId = 2018;
-/// XC-20 assets to perform an origin check on.
-pub const ASSET_PRECOMPILE_IDS: &[AssetId] = &[NUSD_PRECOMPILE_ID; 1];
+
+/// Resolves an account to the EVM address its DID authenticates with, as long as that address maps
+/// back to the account. Used to return council members as addresses.
+pub struct DidAuthenticationAddress<R>(PhantomData<R>);
+
+impl<R> AccountToAddress<R::AccountId> for DidAuthenticationAddress<R>
+where
+	R: pallet_evm::Config + pallet_did::Config<AuthenticationAddress = H160>,
+{
+	fn address_of(account: &R::AccountId) -> Option<H160> {
+		let document = pallet_did::Pallet::<R>::dids(R::DidIdentifier::from(account.clone()))?;
+		let address = document.authentication.controller;
+		(R::AddressMapping::into_account_id(address) == *account).then_some(address)
+	}
+}
 
 /// The PrecompileSet installed in the Astar runtime.
 #[derive(Debug, Default, Clone, Copy)]
@@ -57,9 +73,12 @@ impl<R> FrontierPrecompiles<R> {
 	/// Return all addresses that contain precompiles. This can be used to populate dummy code
 	/// under the precompile.
 	pub fn used_addresses() -> impl Iterator<Item = H160> {
-		sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 20481, 20482, 20483, 20484]
-			.into_iter()
-			.map(hash)
+		sp_std::vec![
+			1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 1028, 1029, 20481, 20482, 20483,
+			20484
+		]
+		.into_iter()
+		.map(hash)
 	}
 }
 
@@ -69,11 +88,15 @@ impl<R> FrontierPrecompiles<R> {
 impl<R> pallet_evm::PrecompileSet for FrontierPrecompiles<R>
 where
 	Erc20AssetsPrecompileSet<R>: PrecompileSet,
-	<R as pallet_assets::Config>::AssetId: From<AssetId>,
 	Dispatch<R>: Precompile,
+	WatrBatchPrecompile<R>: Precompile,
+	WatrGovernancePrecompile<R, CouncilCollective, DidAuthenticationAddress<R>>: Precompile,
+	WatrXcmPrecompile<R>: Precompile,
 	R: pallet_evm::Config
 		+ pallet_assets::Config
+		+ pallet_asset_policy::Config<AssetId = <R as pallet_assets::Config>::AssetId>
 		+ pallet_xcm::Config
+		+ pallet_xc_asset_config::Config<AssetId = <R as pallet_assets::Config>::AssetId>
 		+ AddressToAssetId<<R as pallet_assets::Config>::AssetId>
 		+ frame_system::Config,
 {
@@ -105,17 +128,24 @@ where
 			// nor Ethereum precompiles :
 			a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
 			a if a == hash(1025) => Some(Dispatch::<R>::execute(handle)),
+			a if a == hash(1027) => Some(WatrXcmPrecompile::<R>::execute(handle)),
+			a if a == hash(1028) => Some(WatrBatchPrecompile::<R>::execute(handle)),
+			a if a == hash(1029) => Some(WatrGovernancePrecompile::<
+				R,
+				CouncilCollective,
+				DidAuthenticationAddress<R>,
+			>::execute(handle)),
 			// If the address matches asset prefix, the we route through the asset precompile set
 			a if &a.to_fixed_bytes()[0..4] == ASSET_PRECOMPILE_ADDRESS_PREFIX => {
-				// Get asset id to check if it is NUSD
-				let asset_id = R::address_to_asset_id(a).unwrap_or(0.into());
-				// If asset needs an origin check, ensure origin is the issuer of the asset. May return error.
+				// If the asset precompile is restricted, ensure the caller is allowed to use it.
 				// This is useful for limiting precompile use to a smart contract.
-				if ASSET_PRECOMPILE_IDS.iter().find(|&&a| asset_id == a.into()).is_some() {
-					let owner = pallet_assets::Pallet::<R>::issuer(asset_id);
-					let origin = R::AddressMapping::into_account_id(handle.context().caller);
+				if let Some(asset_id) = R::address_to_asset_id(a) {
+					let caller = handle.context().caller;
+					let origin = R::AddressMapping::into_account_id(caller);
 
-					if Some(origin.clone()) != owner.clone() {
+					if !pallet_asset_policy::Pallet::<R>::is_precompile_caller_allowed(
+						asset_id, caller, &origin,
+					) {
 						return Some(Err(error("bad origin for asset precompile")));
 					}
 				}
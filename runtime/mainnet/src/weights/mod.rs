@@ -26,18 +26,19 @@ pub mod pallet_collective;
 pub mod pallet_did;
 pub mod pallet_identity;
 pub mod pallet_membership;
+pub mod pallet_motion;
 pub mod pallet_multisig;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_scheduler;
 pub mod pallet_session;
-pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_xc_asset_config;
 pub mod paritydb_weights;
 pub mod rocksdb_weights;
+pub mod xcm;
 
 pub use block_weights::constants::BlockExecutionWeight;
 pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
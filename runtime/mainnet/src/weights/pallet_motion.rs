@@ -0,0 +1,159 @@
+// Copyright 2023 Watr Foundation
+// This file is part of Watr.
+
+// Watr is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Watr is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Watr.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_motion`
+//!
+//! PLACEHOLDER VALUES, NOT MEASURED. `pallet_motion` has not been benchmarked on reference
+//! hardware yet. These mirror the estimates shipped with the pallet and must be replaced by
+//! running:
+//!
+//! target/release/watr-node benchmark pallet --chain=mainnet-dev --wasm-execution=compiled
+//! --pallet=pallet_motion --extrinsic=* --steps=50 --repeat=20 --json
+//! --header=./file_header.txt --output=./runtime/mainnet/src/weights/pallet_motion.rs
+//!
+//! They only cover the overhead of the motion itself. The weight of the dispatched calls is
+//! added by the pallet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_motion`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_motion::WeightInfo for WeightInfo<T> {
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn simple_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn super_majority() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn unanimous() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn execute_ready() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:2)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	fn cancel() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_500))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn dispatch_as_motion_origin() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[0, 32]`.
+	/// Storage: Council Voting (r:1 w:0)
+	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Motion ExecutedCount (r:1 w:1)
+	/// Proof: Motion ExecutedCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Motion MotionHistory (r:0 w:1)
+	/// Proof: Motion MotionHistory (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn batch(c: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 100).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Motion PendingMotions (r:1 w:1)
+	/// Proof Skipped: Motion PendingMotions (max_values: None, max_size: None, mode: Measured)
+	fn fast_track() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Motion CancellationExpiries (r:1 w:1)
+	/// Proof: Motion CancellationExpiries (max_values: Some(1), max_size: Some(9218), added: 9713, mode: MaxEncodedLen)
+	/// Storage: Motion CancelledMotions (r:0 w:1)
+	/// Proof: Motion CancelledMotions (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 256]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9_713))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+}
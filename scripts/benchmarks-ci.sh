@@ -17,6 +17,13 @@ pallets=($(
 for pallet in ${pallets[@]}
 do
 	output_file="${pallet//::/_}"
+	extra_args=""
+
+	# XCM instruction weights live in their own module and are consumed by `WeightInfoBounds`.
+	if [[ $pallet == pallet_xcm_benchmarks::* ]]; then
+		output_file="xcm/${output_file}"
+		extra_args="--template=./scripts/xcm-bench-template.hbs"
+	fi
 
     target/release/watr-node benchmark pallet \
 		--chain=$benchmarkRuntimeName \
@@ -27,5 +34,6 @@ do
 		--repeat=$repeat \
 		--json \
 		--header=./file_header.txt \
+		$extra_args \
 		--output="${benchmarkOutput}/${output_file}.rs"
 done